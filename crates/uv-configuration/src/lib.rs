@@ -13,6 +13,7 @@ pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
+pub use prerelease::*;
 pub use preview::*;
 pub use project_build_backend::*;
 pub use required_version::*;
@@ -38,6 +39,7 @@ mod install_options;
 mod name_specifiers;
 mod overrides;
 mod package_options;
+mod prerelease;
 mod preview;
 mod project_build_backend;
 mod required_version;
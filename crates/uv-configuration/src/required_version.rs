@@ -69,6 +69,12 @@ impl<'de> serde::Deserialize<'de> for RequiredVersion {
     }
 }
 
+impl serde::Serialize for RequiredVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl std::fmt::Display for RequiredVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(&self.0, f)
@@ -3,8 +3,10 @@ pub use download::LocalWheel;
 pub use error::Error;
 pub use index::{BuiltWheelIndex, RegistryWheelIndex};
 pub use metadata::{
-    ArchiveMetadata, BuildRequires, ExtraBuildRequires, FlatRequiresDist, LoweredRequirement,
-    LoweringError, Metadata, MetadataError, RequiresDist, SourcedDependencyGroups,
+    ArchiveMetadata, BuildRequires, CacheStats, DefinedIn, DependencyGroupsOptions, EditablePolicy,
+    ExtraBuildRequires, FlatRequiresDist, GroupNameValidator, GroupResolver, LoweredRequirement,
+    LoweringError, MergeOrigin, Metadata, MetadataError, MetadataWarning, RequiresDist,
+    ResolvedSource, SourceKind, SourcedDependencyGroups, SpecifierShape, UrlRewrite, WarningSink,
 };
 pub use reporter::Reporter;
 pub use source::prune;
@@ -9,11 +9,14 @@ use uv_distribution_filename::DistExtension;
 use uv_distribution_types::{
     Index, IndexLocations, IndexMetadata, IndexName, Origin, Requirement, RequirementSource,
 };
+use uv_fs::Simplified;
 use uv_git_types::{GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl, looks_like_git_repository};
-use uv_pypi_types::{ConflictItem, ParsedGitUrl, ParsedUrlError, VerbatimParsedUrl};
+use uv_pypi_types::{
+    ConflictItem, ParsedGitUrl, ParsedUrlError, VerbatimParsedUrl, get_subdirectory,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_workspace::Workspace;
 use uv_workspace::pyproject::{PyProjectToml, Source, Sources};
@@ -211,6 +214,7 @@ impl LoweredRequirement {
                             marker,
                             extra,
                             group,
+                            ..
                         } => {
                             // Identify the named index from either the project indexes or the workspace indexes,
                             // in that order.
@@ -294,9 +298,7 @@ impl LoweredRequirement {
                                 // If the workspace comes from a Git dependency, all workspace
                                 // members need to be Git dependencies, too.
                                 let subdirectory =
-                                    uv_fs::relative_to(member.root(), git_member.fetch_root)
-                                        .expect("Workspace member must be relative");
-                                let subdirectory = uv_fs::normalize_path_buf(subdirectory);
+                                    checkout_subdirectory(member.root(), git_member.fetch_root)?;
                                 RequirementSource::Git {
                                     git: git_member.git_source.git.clone(),
                                     subdirectory: if subdirectory == PathBuf::new() {
@@ -554,7 +556,7 @@ pub enum LoweringError {
     RelativeTo(io::Error),
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SourceKind {
     Path,
     Url,
@@ -562,6 +564,20 @@ pub enum SourceKind {
     Registry,
 }
 
+impl SourceKind {
+    /// Classify a lowered [`RequirementSource`].
+    ///
+    /// Both local files and directories are classified as [`SourceKind::Path`].
+    pub fn from_source(source: &RequirementSource) -> Self {
+        match source {
+            RequirementSource::Registry { .. } => Self::Registry,
+            RequirementSource::Url { .. } => Self::Url,
+            RequirementSource::Git { .. } => Self::Git,
+            RequirementSource::Path { .. } | RequirementSource::Directory { .. } => Self::Path,
+        }
+    }
+}
+
 impl std::fmt::Display for SourceKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -615,13 +631,31 @@ fn git_source(
 /// Convert a URL source into a [`RequirementSource`].
 fn url_source(
     requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
-    url: DisplaySafeUrl,
-    subdirectory: Option<Box<Path>>,
+    mut url: DisplaySafeUrl,
+    mut subdirectory: Option<Box<Path>>,
 ) -> Result<RequirementSource, LoweringError> {
-    let mut verbatim_url = url.clone();
-    if verbatim_url.fragment().is_some() {
-        return Err(LoweringError::ForbiddenFragment(url));
+    // Allow a `subdirectory` to be provided via the fragment (e.g., `#subdirectory=pkg`, or the
+    // legacy `#egg=pkg&subdirectory=pkg`), as for PEP 508 URLs. Any other fragment is rejected.
+    if let Some(fragment) = url.fragment() {
+        if !fragment
+            .split('&')
+            .all(|part| part.starts_with("subdirectory=") || part.starts_with("egg="))
+        {
+            return Err(LoweringError::ForbiddenFragment(url));
+        }
+        if let Some(fragment_subdirectory) = get_subdirectory(&url) {
+            if subdirectory
+                .as_deref()
+                .is_some_and(|subdirectory| subdirectory != fragment_subdirectory)
+            {
+                return Err(LoweringError::ForbiddenFragment(url));
+            }
+            subdirectory = Some(fragment_subdirectory.into_boxed_path());
+        }
+        url.set_fragment(None);
     }
+
+    let mut verbatim_url = url.clone();
     if let Some(subdirectory) = subdirectory.as_ref() {
         let subdirectory = subdirectory
             .to_str()
@@ -676,6 +710,30 @@ fn registry_source(
     }
 }
 
+/// Compute the path of `path` relative to the Git checkout at `fetch_root`, i.e., its
+/// subdirectory within the repository.
+///
+/// The paths are first compared lexically. If `path` appears to lie outside the checkout, both
+/// paths are canonicalized and compared again, since one of them may have been reached through a
+/// symlink or, on Windows, a directory junction (e.g., a checkout whose root was canonicalized
+/// during discovery, while the fetch root was not).
+fn checkout_subdirectory(path: &Path, fetch_root: &Path) -> Result<PathBuf, LoweringError> {
+    let escapes = |relative: &Path| relative.starts_with("..");
+    let relative = uv_fs::relative_to(path, fetch_root).map_err(LoweringError::RelativeTo);
+    if relative.as_deref().is_ok_and(|relative| !escapes(relative)) {
+        return relative.map(uv_fs::normalize_path_buf);
+    }
+
+    // Resolve any symlinks and junctions, and try again.
+    let canonical = path
+        .simple_canonicalize()
+        .and_then(|path| uv_fs::relative_to(path, fetch_root.simple_canonicalize()?));
+    match canonical {
+        Ok(canonical) if !escapes(&canonical) => Ok(uv_fs::normalize_path_buf(canonical)),
+        _ => relative.map(uv_fs::normalize_path_buf),
+    }
+}
+
 /// Convert a path string to a file or directory source.
 fn path_source(
     path: impl AsRef<Path>,
@@ -704,9 +762,7 @@ fn path_source(
     if is_dir {
         if let Some(git_member) = git_member {
             let git = git_member.git_source.git.clone();
-            let subdirectory = uv_fs::relative_to(install_path, git_member.fetch_root)
-                .expect("Workspace member must be relative");
-            let subdirectory = uv_fs::normalize_path_buf(subdirectory);
+            let subdirectory = checkout_subdirectory(&install_path, git_member.fetch_root)?;
             let subdirectory = if subdirectory == PathBuf::new() {
                 None
             } else {
@@ -1,20 +1,28 @@
 use std::collections::BTreeMap;
-use std::path::Path;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use thiserror::Error;
 
-use uv_configuration::SourceStrategy;
-use uv_distribution_types::{GitSourceUrl, IndexLocations, Requirement};
+use uv_configuration::{RequiredVersion, SourceStrategy};
+use uv_distribution_types::{GitSourceUrl, IndexLocations, IndexName, Requirement};
+use uv_fs::Simplified;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{HashDigests, ResolutionMetadata};
-use uv_workspace::dependency_groups::DependencyGroupError;
+use uv_workspace::dependency_groups::{DependencyGroupError, DependencyGroupErrorInner};
+use uv_workspace::pyproject::Source;
 use uv_workspace::{WorkspaceCache, WorkspaceError};
 
 pub use crate::metadata::build_requires::{BuildRequires, ExtraBuildRequires};
-pub use crate::metadata::dependency_groups::SourcedDependencyGroups;
+pub use crate::metadata::dependency_groups::{
+    CacheStats, DefinedIn, DependencyGroupsOptions, EditablePolicy, GroupNameValidator,
+    GroupResolver, MergeOrigin, ResolvedSource, SourcedDependencyGroups, SpecifierShape,
+    UrlRewrite, WarningSink,
+};
 pub use crate::metadata::lowering::LoweredRequirement;
-pub use crate::metadata::lowering::LoweringError;
+pub use crate::metadata::lowering::{LoweringError, SourceKind};
 pub use crate::metadata::requires_dist::{FlatRequiresDist, RequiresDist};
 
 mod build_requires;
@@ -27,7 +35,13 @@ pub enum MetadataError {
     #[error(transparent)]
     Workspace(#[from] WorkspaceError),
     #[error(transparent)]
-    DependencyGroup(#[from] DependencyGroupError),
+    DependencyGroup(DependencyGroupError),
+    /// A `dependency-groups` entry specifies both `include-group` and `requirement`.
+    ///
+    /// This is a [`DependencyGroupError`] like any other malformed group, but is surfaced as its
+    /// own variant (and code), such that callers can match on it directly.
+    #[error(transparent)]
+    AmbiguousGroupEntry(DependencyGroupError),
     #[error("Failed to parse entry: `{0}`")]
     LoweringError(PackageName, #[source] Box<LoweringError>),
     #[error("Failed to parse entry in group `{0}`: `{1}`")]
@@ -40,6 +54,10 @@ pub enum MetadataError {
         "Source entry for `{0}` only applies to extra `{1}`, but `{0}` was not found under the `project.optional-dependencies` section for that extra. When an extra is present on a source (e.g., `extra = \"{1}\"`), the relevant package must be included in the `project.optional-dependencies` section for that extra (e.g., `project.optional-dependencies = {{ \"{1}\" = [\"{0}\"] }}`)."
     )]
     IncompleteSourceExtra(PackageName, ExtraName),
+    #[error(
+        "Extra `{1}` of `{0}` includes itself. A self-referential requirement in a dependency group (e.g., `{0}[{1}]`) is expanded into the `project.optional-dependencies` section for that extra, which must not require `{0}[{1}]`, directly or transitively."
+    )]
+    SelfExtraCycle(PackageName, ExtraName),
     #[error(
         "Source entry for `{0}` only applies to dependency group `{1}`, but the `{1}` group does not exist. When a group is present on a source (e.g., `group = \"{1}\"`), the relevant package must be included in the `dependency-groups` section for that extra (e.g., `dependency-groups = {{ \"{1}\" = [\"{0}\"] }}`)."
     )]
@@ -48,6 +66,316 @@ pub enum MetadataError {
         "Source entry for `{0}` only applies to dependency group `{1}`, but `{0}` was not found under the `dependency-groups` section for that group. When a group is present on a source (e.g., `group = \"{1}\"`), the relevant package must be included in the `dependency-groups` section for that extra (e.g., `dependency-groups = {{ \"{1}\" = [\"{0}\"] }}`)."
     )]
     IncompleteSourceGroup(PackageName, GroupName),
+    #[error(
+        "Source entry for `{0}` only applies to dependency group `{1}`, but the `dependency-groups` table is empty. When a group is present on a source (e.g., `group = \"{1}\"`), the relevant package must be included in the `dependency-groups` section for that group (e.g., `dependency-groups = {{ \"{1}\" = [\"{0}\"] }}`)."
+    )]
+    EmptySourceGroups(PackageName, GroupName),
+    #[error("Constraints file for dependency group `{0}` does not exist: `{}`", _1.user_display())]
+    MissingGroupConstraints(GroupName, PathBuf),
+    #[error("Git checkout has no parent directory: `{}`", _0.user_display())]
+    GitCheckoutHasNoParent(PathBuf),
+    #[error(
+        "Source entry for `{0}` points to a directory that does not appear to be a Python package: `{}`. A package directory must contain a `pyproject.toml`, `setup.py`, or `setup.cfg` file, or a `src` directory.", _1.user_display()
+    )]
+    PathSourceNotAPackage(PackageName, PathBuf),
+    #[error(
+        "Source entry for `{0}` requires uv `{1}`, but the current version is `{}`",
+        uv_version::version()
+    )]
+    SourceRequiresNewerUv(PackageName, RequiredVersion),
+    #[error(
+        "Requirement for `{0}` includes a URL (e.g., `{0} @ https://...`), but `{0}` also has an entry in `tool.uv.sources`. Remove either the inline URL or the source entry."
+    )]
+    DuplicateSourceSpecification(PackageName),
+    #[error(
+        "Source entry for `{0}` points to a file that is not a distribution archive: `{}`. A path source must point to a package directory or to a wheel or source distribution.", _1.user_display()
+    )]
+    SourcePathNotDirectory(PackageName, PathBuf),
+    #[error("Source entry for `{0}` points to a distribution archive that does not exist: `{}`", _1.user_display())]
+    SourcePathNotFound(PackageName, PathBuf),
+    #[error("Index `{0}` references the environment variable `{1}`, which is not set")]
+    UnresolvedIndexPlaceholder(String, String),
+    #[error("Index `{0}` has an invalid URL after expanding environment variables")]
+    InvalidExpandedIndexUrl(String),
+    #[error("Index name `{0}` is used by multiple entries in `tool.uv.index`")]
+    DuplicateIndexName(String),
+    #[error("Sources file must reside within the project root: `{}`", _0.user_display())]
+    SourcesFileOutsideProject(PathBuf),
+    #[error("Failed to read sources file: `{}`", _0.user_display())]
+    ReadSourcesFile(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse sources file: `{}`", _0.user_display())]
+    ParseSourcesFile(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Group `{0}` is not defined in the project's `dependency-groups` table")]
+    MissingGroup(GroupName),
+    #[error("Lowering of dependency groups was cancelled")]
+    Cancelled,
+    #[error(
+        "Dependency groups contain {0} requirements, which exceeds the limit of {1} requirements"
+    )]
+    ConfigTooLarge(usize, usize),
+    #[error(
+        "A synthetic `all` group was requested, but the project already declares a dependency group named `all`"
+    )]
+    SyntheticAllConflict,
+    #[error(
+        "Group alias `{0}` refers to `{1}`, which is not defined in the project's `dependency-groups` table"
+    )]
+    GroupAliasTargetNotFound(GroupName, GroupName),
+    #[error("Group alias `{0}` refers to itself, directly or transitively")]
+    GroupAliasCycle(GroupName),
+    #[error(
+        "Group alias `{0}` has the same name as a group in the project's `dependency-groups` table"
+    )]
+    GroupAliasShadowsGroup(GroupName),
+    #[error("Project declares {0} dependency groups, which exceeds the configured limit")]
+    TooManyGroups(usize),
+    #[error(
+        "Source entry for `{0}` is marked as `workspace = true`, but `{0}` is not a workspace member"
+    )]
+    UnknownWorkspaceMember(PackageName),
+    #[error(
+        "Source entry for `{0}` points to the project itself, but the project has no `[project]` table. A `pyproject.toml` that only declares `[dependency-groups]` can't be installed as a package."
+    )]
+    SelfSourceWithoutProject(PackageName),
+    #[error("Environment overlay `{0}` is not defined in `tool.uv.environment-overlays`")]
+    MissingEnvironmentOverlay(String),
+    #[error(
+        "Environment overlay `{0}` modifies dependency group `{1}`, which is not defined in the project's `dependency-groups` table. To introduce a new group, add it to the overlay's `new-groups`."
+    )]
+    UndeclaredOverlayGroup(String, GroupName),
+    #[error("`tool.uv.sources` contains an entry with an empty package name")]
+    EmptySourceKey,
+    #[error("`dependency-groups` contains a group with an empty name")]
+    EmptyGroupName,
+    #[error(
+        "Dependency group `{0}` requires a version of `{1}` that's excluded by `tool.uv.constraint-dependencies`"
+    )]
+    GroupConstraintConflict(GroupName, PackageName),
+    #[error(
+        "`tool.uv.dev-dependencies` is not allowed; declare development dependencies in the `dev` group of the `[dependency-groups]` table instead"
+    )]
+    LegacyDevDependenciesForbidden,
+    #[error("Dependency group `{0}` has an invalid name: {1}")]
+    InvalidGroupName(GroupName, String),
+    #[error("Dependency group `{0}` includes group `{1}`, which is empty")]
+    IncludesEmptyGroup(GroupName, GroupName),
+    #[error(
+        "Dependency group `{0}` sets both `no-sources = true` and `sources = \"enabled\"` in `tool.uv.dependency-groups`"
+    )]
+    ConflictingGroupSourceStrategy(GroupName),
+    #[error(
+        "Git source for `{0}` does not pin a tag, revision, or commit, and instead tracks a branch"
+    )]
+    UnpinnedGitSource(PackageName),
+    #[error(
+        "Source entry for `{0}` references index `{1}`, which is not defined in `tool.uv.index`{}",
+        _2.as_ref().map(|name| format!(" (did you mean `{name}`?)")).unwrap_or_default()
+    )]
+    IndexReferencedButUndefined(PackageName, IndexName, Option<IndexName>),
+    #[error("URL rewrite for prefix `{0}` produced an invalid URL")]
+    InvalidRewrittenUrl(String),
+    #[error("Failed to serialize the source for `{0}`")]
+    SourceSerialization(PackageName, #[source] Box<toml_edit::ser::Error>),
+}
+
+impl MetadataError {
+    /// Return a stable, machine-readable code identifying the kind of error.
+    ///
+    /// Codes are never reused or reassigned; new variants receive new codes.
+    ///
+    /// | Code         | Variant                                           |
+    /// |--------------|---------------------------------------------------|
+    /// | `UV-WS-001`  | [`MetadataError::Workspace`]                      |
+    /// | `UV-GRP-001` | [`MetadataError::MissingSourceGroup`]             |
+    /// | `UV-GRP-002` | [`MetadataError::IncompleteSourceGroup`]          |
+    /// | `UV-GRP-003` | [`MetadataError::EmptySourceGroups`]              |
+    /// | `UV-GRP-004` | [`MetadataError::DependencyGroup`]                |
+    /// | `UV-GRP-005` | [`MetadataError::GroupLoweringError`]             |
+    /// | `UV-GRP-006` | [`MetadataError::MissingGroupConstraints`]        |
+    /// | `UV-GRP-007` | [`MetadataError::MissingGroup`]                   |
+    /// | `UV-GRP-008` | [`MetadataError::Cancelled`]                      |
+    /// | `UV-GRP-009` | [`MetadataError::ConfigTooLarge`]                 |
+    /// | `UV-GRP-010` | [`MetadataError::SyntheticAllConflict`]           |
+    /// | `UV-GRP-011` | [`MetadataError::GroupAliasTargetNotFound`]       |
+    /// | `UV-GRP-012` | [`MetadataError::GroupAliasCycle`]                |
+    /// | `UV-GRP-013` | [`MetadataError::TooManyGroups`]                  |
+    /// | `UV-GRP-014` | [`MetadataError::MissingEnvironmentOverlay`]      |
+    /// | `UV-GRP-015` | [`MetadataError::UndeclaredOverlayGroup`]         |
+    /// | `UV-GRP-016` | [`MetadataError::EmptyGroupName`]                 |
+    /// | `UV-GRP-017` | [`MetadataError::GroupConstraintConflict`]        |
+    /// | `UV-GRP-018` | [`MetadataError::LegacyDevDependenciesForbidden`] |
+    /// | `UV-GRP-019` | [`MetadataError::InvalidGroupName`]               |
+    /// | `UV-GRP-020` | [`MetadataError::IncludesEmptyGroup`]             |
+    /// | `UV-GRP-021` | [`MetadataError::ConflictingGroupSourceStrategy`] |
+    /// | `UV-GRP-022` | [`MetadataError::GroupAliasShadowsGroup`]         |
+    /// | `UV-GRP-023` | [`MetadataError::AmbiguousGroupEntry`]            |
+    /// | `UV-EXT-001` | [`MetadataError::MissingSourceExtra`]             |
+    /// | `UV-EXT-002` | [`MetadataError::IncompleteSourceExtra`]          |
+    /// | `UV-EXT-003` | [`MetadataError::SelfExtraCycle`]                 |
+    /// | `UV-SRC-001` | [`MetadataError::LoweringError`]                  |
+    /// | `UV-SRC-002` | [`MetadataError::GitCheckoutHasNoParent`]         |
+    /// | `UV-SRC-003` | [`MetadataError::PathSourceNotAPackage`]          |
+    /// | `UV-SRC-004` | [`MetadataError::SourceRequiresNewerUv`]          |
+    /// | `UV-SRC-005` | [`MetadataError::SourcesFileOutsideProject`]      |
+    /// | `UV-SRC-006` | [`MetadataError::ReadSourcesFile`]                |
+    /// | `UV-SRC-007` | [`MetadataError::ParseSourcesFile`]               |
+    /// | `UV-SRC-008` | [`MetadataError::DuplicateSourceSpecification`]   |
+    /// | `UV-SRC-009` | [`MetadataError::SourcePathNotDirectory`]         |
+    /// | `UV-SRC-010` | [`MetadataError::SourcePathNotFound`]             |
+    /// | `UV-SRC-011` | [`MetadataError::UnknownWorkspaceMember`]         |
+    /// | `UV-SRC-012` | [`MetadataError::SelfSourceWithoutProject`]       |
+    /// | `UV-SRC-013` | [`MetadataError::EmptySourceKey`]                 |
+    /// | `UV-SRC-014` | [`MetadataError::UnpinnedGitSource`]              |
+    /// | `UV-SRC-015` | [`MetadataError::IndexReferencedButUndefined`]    |
+    /// | `UV-SRC-016` | [`MetadataError::InvalidRewrittenUrl`]            |
+    /// | `UV-SRC-017` | [`MetadataError::SourceSerialization`]            |
+    /// | `UV-IDX-001` | [`MetadataError::UnresolvedIndexPlaceholder`]     |
+    /// | `UV-IDX-002` | [`MetadataError::InvalidExpandedIndexUrl`]        |
+    /// | `UV-IDX-003` | [`MetadataError::DuplicateIndexName`]             |
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Workspace(_) => "UV-WS-001",
+            Self::MissingSourceGroup(..) => "UV-GRP-001",
+            Self::IncompleteSourceGroup(..) => "UV-GRP-002",
+            Self::EmptySourceGroups(..) => "UV-GRP-003",
+            Self::DependencyGroup(_) => "UV-GRP-004",
+            Self::GroupLoweringError(..) => "UV-GRP-005",
+            Self::MissingGroupConstraints(..) => "UV-GRP-006",
+            Self::MissingGroup(_) => "UV-GRP-007",
+            Self::Cancelled => "UV-GRP-008",
+            Self::ConfigTooLarge(..) => "UV-GRP-009",
+            Self::SyntheticAllConflict => "UV-GRP-010",
+            Self::GroupAliasTargetNotFound(..) => "UV-GRP-011",
+            Self::GroupAliasCycle(_) => "UV-GRP-012",
+            Self::TooManyGroups(_) => "UV-GRP-013",
+            Self::MissingEnvironmentOverlay(_) => "UV-GRP-014",
+            Self::UndeclaredOverlayGroup(..) => "UV-GRP-015",
+            Self::EmptyGroupName => "UV-GRP-016",
+            Self::GroupConstraintConflict(..) => "UV-GRP-017",
+            Self::LegacyDevDependenciesForbidden => "UV-GRP-018",
+            Self::InvalidGroupName(..) => "UV-GRP-019",
+            Self::IncludesEmptyGroup(..) => "UV-GRP-020",
+            Self::ConflictingGroupSourceStrategy(_) => "UV-GRP-021",
+            Self::GroupAliasShadowsGroup(_) => "UV-GRP-022",
+            Self::AmbiguousGroupEntry(_) => "UV-GRP-023",
+            Self::MissingSourceExtra(..) => "UV-EXT-001",
+            Self::IncompleteSourceExtra(..) => "UV-EXT-002",
+            Self::SelfExtraCycle(..) => "UV-EXT-003",
+            Self::LoweringError(..) => "UV-SRC-001",
+            Self::GitCheckoutHasNoParent(_) => "UV-SRC-002",
+            Self::PathSourceNotAPackage(..) => "UV-SRC-003",
+            Self::SourceRequiresNewerUv(..) => "UV-SRC-004",
+            Self::SourcesFileOutsideProject(_) => "UV-SRC-005",
+            Self::ReadSourcesFile(..) => "UV-SRC-006",
+            Self::ParseSourcesFile(..) => "UV-SRC-007",
+            Self::DuplicateSourceSpecification(_) => "UV-SRC-008",
+            Self::SourcePathNotDirectory(..) => "UV-SRC-009",
+            Self::SourcePathNotFound(..) => "UV-SRC-010",
+            Self::UnknownWorkspaceMember(_) => "UV-SRC-011",
+            Self::SelfSourceWithoutProject(_) => "UV-SRC-012",
+            Self::EmptySourceKey => "UV-SRC-013",
+            Self::UnpinnedGitSource(_) => "UV-SRC-014",
+            Self::IndexReferencedButUndefined(..) => "UV-SRC-015",
+            Self::InvalidRewrittenUrl(_) => "UV-SRC-016",
+            Self::SourceSerialization(..) => "UV-SRC-017",
+            Self::UnresolvedIndexPlaceholder(..) => "UV-IDX-001",
+            Self::InvalidExpandedIndexUrl(_) => "UV-IDX-002",
+            Self::DuplicateIndexName(_) => "UV-IDX-003",
+        }
+    }
+
+    /// Return the byte range within the `pyproject.toml` to which the error refers, if known.
+    ///
+    /// Spans are only available for malformed dependency groups
+    /// (see [`MetadataError::DependencyGroup`] and [`MetadataError::AmbiguousGroupEntry`]).
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Self::DependencyGroup(err) | Self::AmbiguousGroupEntry(err) => err.span(),
+            _ => None,
+        }
+    }
+}
+
+/// A non-fatal diagnostic emitted while reading metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataWarning {
+    /// A package is constrained to disjoint versions by an extra and a dependency group of the
+    /// same name, such that the two can't be installed together.
+    ExtraGroupVersionConflict {
+        package: PackageName,
+        extra: ExtraName,
+        group: GroupName,
+    },
+    /// A path source points into a Git checkout with uncommitted changes, such that the
+    /// resolved package may not be reproducible.
+    DirtyGitSource { package: PackageName, path: PathBuf },
+    /// A package in the legacy `tool.uv.dev-dependencies` also arrives in the `dev` group via an
+    /// `include-group` entry, such that merging the two would duplicate it.
+    ///
+    /// If the included requirement subsumes the dev dependency, the dev dependency is removed;
+    /// otherwise (e.g., if the two have different specifiers), both are retained.
+    DevDependencyAlsoIncluded {
+        package: PackageName,
+        group: GroupName,
+        removed: bool,
+    },
+    /// A path source couldn't be made relative to the requested base directory (e.g., as it's on
+    /// a different drive on Windows), and was left as an absolute path.
+    UnrelativizablePath { package: PackageName, path: PathBuf },
+    /// Workspace discovery failed, and the project was instead treated as standalone, without
+    /// any other workspace members.
+    StandaloneFallback { reason: String },
+    /// A requirement override matches no requirement in any dependency group.
+    UnusedOverride { package: PackageName },
+}
+
+impl std::fmt::Display for MetadataWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExtraGroupVersionConflict {
+                package,
+                extra,
+                group,
+            } => write!(
+                f,
+                "`{package}` is constrained to incompatible versions by the `{extra}` extra and the `{group}` dependency group"
+            ),
+            Self::DirtyGitSource { package, path } => write!(
+                f,
+                "The source for `{package}` points to a Git checkout with uncommitted changes: `{}`",
+                path.user_display()
+            ),
+            Self::DevDependencyAlsoIncluded {
+                package,
+                group,
+                removed: true,
+            } => write!(
+                f,
+                "`{package}` is declared in `tool.uv.dev-dependencies`, but is already included in the `dev` group via the `{group}` group, so the duplicate was removed"
+            ),
+            Self::DevDependencyAlsoIncluded {
+                package,
+                group,
+                removed: false,
+            } => write!(
+                f,
+                "`{package}` is declared in `tool.uv.dev-dependencies`, but is already included in the `dev` group via the `{group}` group with a different requirement, so both were retained"
+            ),
+            Self::UnrelativizablePath { package, path } => write!(
+                f,
+                "The path source for `{package}` could not be made relative, and was left as-is: `{}`",
+                path.user_display()
+            ),
+            Self::StandaloneFallback { reason } => write!(
+                f,
+                "Workspace discovery failed, so the project was treated as standalone: {reason}"
+            ),
+            Self::UnusedOverride { package } => write!(
+                f,
+                "The override for `{package}` does not match any requirement in the dependency groups"
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -146,6 +474,15 @@ impl ArchiveMetadata {
     }
 }
 
+impl From<DependencyGroupError> for MetadataError {
+    fn from(err: DependencyGroupError) -> Self {
+        match err.inner() {
+            DependencyGroupErrorInner::AmbiguousGroupEntry(_) => Self::AmbiguousGroupEntry(err),
+            _ => Self::DependencyGroup(err),
+        }
+    }
+}
+
 impl From<Metadata> for ArchiveMetadata {
     fn from(metadata: Metadata) -> Self {
         Self {
@@ -163,3 +500,271 @@ pub struct GitWorkspaceMember<'a> {
     pub fetch_root: &'a Path,
     pub git_source: &'a GitSourceUrl<'a>,
 }
+
+/// Ensure that the running version of uv satisfies the `requires-uv` constraint of a source, if
+/// any.
+fn validate_requires_uv(name: &PackageName, source: &Source) -> Result<(), MetadataError> {
+    let Some(requires_uv) = source.requires_uv() else {
+        return Ok(());
+    };
+    let Ok(version) = Version::from_str(uv_version::version()) else {
+        return Ok(());
+    };
+    if requires_uv.contains(&version) {
+        Ok(())
+    } else {
+        Err(MetadataError::SourceRequiresNewerUv(
+            name.clone(),
+            requires_uv.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use indoc::indoc;
+    use rustc_hash::FxHashSet;
+
+    use uv_configuration::RequiredVersion;
+    use uv_normalize::{ExtraName, GroupName, PackageName};
+    use uv_workspace::WorkspaceError;
+    use uv_workspace::dependency_groups::{FlatDependencyGroups, MergeMode};
+    use uv_workspace::pyproject::{PyProjectToml, ToolUvSources};
+
+    use crate::metadata::{LoweringError, MetadataError};
+
+    #[test]
+    fn codes() -> anyhow::Result<()> {
+        let package = PackageName::from_str("foo")?;
+        let group = GroupName::from_str("dev")?;
+        let extra = ExtraName::from_str("cpu")?;
+        let path = PathBuf::from("foo");
+
+        let pyproject_toml = PyProjectToml::from_string(
+            indoc! {r#"
+                [dependency-groups]
+                dev = [{ include-group = "dev" }]
+            "#}
+            .to_string(),
+        )?;
+        let dependency_group = FlatDependencyGroups::from_pyproject_toml(
+            Path::new(""),
+            &pyproject_toml,
+            &Default::default(),
+            MergeMode::default(),
+        )
+        .unwrap_err();
+        let ambiguous_group_entry = FlatDependencyGroups::from_pyproject_toml(
+            Path::new(""),
+            &PyProjectToml::from_string(
+                indoc! {r#"
+                    [dependency-groups]
+                    dev = [{ requirement = "foo", include-group = "dev" }]
+                "#}
+                .to_string(),
+            )?,
+            &Default::default(),
+            MergeMode::default(),
+        )
+        .unwrap_err();
+        let sources_file = toml::from_str::<ToolUvSources>("foo = 1").unwrap_err();
+
+        let errors = [
+            (
+                MetadataError::Workspace(WorkspaceError::MissingPyprojectToml),
+                "UV-WS-001",
+            ),
+            (
+                MetadataError::MissingSourceGroup(package.clone(), group.clone()),
+                "UV-GRP-001",
+            ),
+            (
+                MetadataError::IncompleteSourceGroup(package.clone(), group.clone()),
+                "UV-GRP-002",
+            ),
+            (
+                MetadataError::EmptySourceGroups(package.clone(), group.clone()),
+                "UV-GRP-003",
+            ),
+            (
+                MetadataError::DependencyGroup(dependency_group),
+                "UV-GRP-004",
+            ),
+            (
+                MetadataError::GroupLoweringError(
+                    group.clone(),
+                    package.clone(),
+                    Box::new(LoweringError::MissingWorkspaceSource(package.clone())),
+                ),
+                "UV-GRP-005",
+            ),
+            (
+                MetadataError::MissingGroupConstraints(group.clone(), path.clone()),
+                "UV-GRP-006",
+            ),
+            (MetadataError::MissingGroup(group.clone()), "UV-GRP-007"),
+            (MetadataError::Cancelled, "UV-GRP-008"),
+            (MetadataError::ConfigTooLarge(2, 1), "UV-GRP-009"),
+            (MetadataError::SyntheticAllConflict, "UV-GRP-010"),
+            (
+                MetadataError::GroupAliasTargetNotFound(group.clone(), group.clone()),
+                "UV-GRP-011",
+            ),
+            (MetadataError::GroupAliasCycle(group.clone()), "UV-GRP-012"),
+            (MetadataError::TooManyGroups(2), "UV-GRP-013"),
+            (
+                MetadataError::MissingEnvironmentOverlay("ci".to_string()),
+                "UV-GRP-014",
+            ),
+            (
+                MetadataError::UndeclaredOverlayGroup("ci".to_string(), group.clone()),
+                "UV-GRP-015",
+            ),
+            (MetadataError::EmptyGroupName, "UV-GRP-016"),
+            (
+                MetadataError::GroupConstraintConflict(group.clone(), package.clone()),
+                "UV-GRP-017",
+            ),
+            (MetadataError::LegacyDevDependenciesForbidden, "UV-GRP-018"),
+            (
+                MetadataError::InvalidGroupName(group.clone(), String::new()),
+                "UV-GRP-019",
+            ),
+            (
+                MetadataError::IncludesEmptyGroup(group.clone(), group.clone()),
+                "UV-GRP-020",
+            ),
+            (
+                MetadataError::ConflictingGroupSourceStrategy(group.clone()),
+                "UV-GRP-021",
+            ),
+            (
+                MetadataError::GroupAliasShadowsGroup(group.clone()),
+                "UV-GRP-022",
+            ),
+            (
+                MetadataError::AmbiguousGroupEntry(ambiguous_group_entry),
+                "UV-GRP-023",
+            ),
+            (
+                MetadataError::MissingSourceExtra(package.clone(), extra.clone()),
+                "UV-EXT-001",
+            ),
+            (
+                MetadataError::IncompleteSourceExtra(package.clone(), extra.clone()),
+                "UV-EXT-002",
+            ),
+            (
+                MetadataError::SelfExtraCycle(package.clone(), extra),
+                "UV-EXT-003",
+            ),
+            (
+                MetadataError::LoweringError(
+                    package.clone(),
+                    Box::new(LoweringError::MissingWorkspaceSource(package.clone())),
+                ),
+                "UV-SRC-001",
+            ),
+            (
+                MetadataError::GitCheckoutHasNoParent(path.clone()),
+                "UV-SRC-002",
+            ),
+            (
+                MetadataError::PathSourceNotAPackage(package.clone(), path.clone()),
+                "UV-SRC-003",
+            ),
+            (
+                MetadataError::SourceRequiresNewerUv(
+                    package.clone(),
+                    RequiredVersion::from_str(">=1000")?,
+                ),
+                "UV-SRC-004",
+            ),
+            (
+                MetadataError::SourcesFileOutsideProject(path.clone()),
+                "UV-SRC-005",
+            ),
+            (
+                MetadataError::ReadSourcesFile(
+                    path.clone(),
+                    std::io::Error::from(std::io::ErrorKind::NotFound),
+                ),
+                "UV-SRC-006",
+            ),
+            (
+                MetadataError::ParseSourcesFile(path.clone(), Box::new(sources_file)),
+                "UV-SRC-007",
+            ),
+            (
+                MetadataError::DuplicateSourceSpecification(package.clone()),
+                "UV-SRC-008",
+            ),
+            (
+                MetadataError::SourcePathNotDirectory(package.clone(), path.clone()),
+                "UV-SRC-009",
+            ),
+            (
+                MetadataError::SourcePathNotFound(package.clone(), path),
+                "UV-SRC-010",
+            ),
+            (
+                MetadataError::UnknownWorkspaceMember(package.clone()),
+                "UV-SRC-011",
+            ),
+            (
+                MetadataError::SelfSourceWithoutProject(package.clone()),
+                "UV-SRC-012",
+            ),
+            (MetadataError::EmptySourceKey, "UV-SRC-013"),
+            (
+                MetadataError::UnpinnedGitSource(package.clone()),
+                "UV-SRC-014",
+            ),
+            (
+                MetadataError::IndexReferencedButUndefined(
+                    package.clone(),
+                    IndexName::from_str("private").unwrap(),
+                    None,
+                ),
+                "UV-SRC-015",
+            ),
+            (
+                MetadataError::InvalidRewrittenUrl("https://github.com/".to_string()),
+                "UV-SRC-016",
+            ),
+            (
+                MetadataError::SourceSerialization(
+                    package,
+                    Box::new(serde::ser::Error::custom("unsupported")),
+                ),
+                "UV-SRC-017",
+            ),
+            (
+                MetadataError::UnresolvedIndexPlaceholder(
+                    "private".to_string(),
+                    "TOKEN".to_string(),
+                ),
+                "UV-IDX-001",
+            ),
+            (
+                MetadataError::InvalidExpandedIndexUrl("private".to_string()),
+                "UV-IDX-002",
+            ),
+            (
+                MetadataError::DuplicateIndexName("private".to_string()),
+                "UV-IDX-003",
+            ),
+        ];
+
+        let mut seen = FxHashSet::default();
+        for (error, code) in &errors {
+            assert_eq!(error.code(), *code, "{error:?}");
+            assert!(seen.insert(error.code()), "duplicate code: {code}");
+        }
+
+        Ok(())
+    }
+}
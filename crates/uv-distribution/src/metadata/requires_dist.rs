@@ -8,12 +8,14 @@ use uv_configuration::SourceStrategy;
 use uv_distribution_types::{IndexLocations, Requirement};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
-use uv_workspace::dependency_groups::FlatDependencyGroups;
+use uv_workspace::dependency_groups::{FlatDependencyGroups, MergeMode};
 use uv_workspace::pyproject::{Sources, ToolUvSources};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, ProjectWorkspace, WorkspaceCache};
 
 use crate::Metadata;
-use crate::metadata::{GitWorkspaceMember, LoweredRequirement, MetadataError};
+use crate::metadata::{
+    GitWorkspaceMember, LoweredRequirement, MetadataError, validate_requires_uv,
+};
 
 #[derive(Debug, Clone)]
 pub struct RequiresDist {
@@ -49,18 +51,24 @@ impl RequiresDist {
         sources: SourceStrategy,
         cache: &WorkspaceCache,
     ) -> Result<Self, MetadataError> {
-        let discovery = DiscoveryOptions {
-            stop_discovery_at: git_member.map(|git_member| {
+        let stop_discovery_at = git_member
+            .map(|git_member| {
                 git_member
                     .fetch_root
                     .parent()
-                    .expect("git checkout has a parent")
-                    .to_path_buf()
-            }),
+                    .map(Path::to_path_buf)
+                    .ok_or_else(|| {
+                        MetadataError::GitCheckoutHasNoParent(git_member.fetch_root.to_path_buf())
+                    })
+            })
+            .transpose()?;
+        let discovery = DiscoveryOptions {
+            stop_discovery_at,
             members: match sources {
                 SourceStrategy::Enabled => MemberDiscovery::default(),
                 SourceStrategy::Disabled => MemberDiscovery::None,
             },
+            ..DiscoveryOptions::default()
         };
         let Some(project_workspace) =
             ProjectWorkspace::from_maybe_project_root(install_path, &discovery, cache).await?
@@ -110,6 +118,8 @@ impl RequiresDist {
         let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
             project_workspace.current_project().root(),
             project_workspace.current_project().pyproject_toml(),
+            project_workspace.workspace().packages(),
+            MergeMode::default(),
         )?;
 
         // Now that we've resolved the dependency groups, we can validate that each source references
@@ -214,6 +224,8 @@ impl RequiresDist {
     ) -> Result<(), MetadataError> {
         for (name, sources) in sources {
             for source in sources.iter() {
+                validate_requires_uv(name, source)?;
+
                 if let Some(extra) = source.extra() {
                     // If the extra doesn't exist at all, error.
                     if !metadata.provides_extras.contains(extra) {
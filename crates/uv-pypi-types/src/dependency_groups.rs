@@ -1,10 +1,12 @@
 use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use uv_normalize::GroupName;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct DependencyGroups(BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>);
 
 impl DependencyGroups {
@@ -18,11 +20,25 @@ impl DependencyGroups {
         self.0.get(group)
     }
 
+    /// Returns a mutable reference to the dependency group with the given name.
+    pub fn get_mut(&mut self, group: &GroupName) -> Option<&mut Vec<DependencyGroupSpecifier>> {
+        self.0.get_mut(group)
+    }
+
     /// Returns `true` if the dependency group is in the list.
     pub fn contains_key(&self, group: &GroupName) -> bool {
         self.0.contains_key(group)
     }
 
+    /// Inserts a dependency group, returning the previous entries of the group, if any.
+    pub fn insert(
+        &mut self,
+        group: GroupName,
+        specifiers: Vec<DependencyGroupSpecifier>,
+    ) -> Option<Vec<DependencyGroupSpecifier>> {
+        self.0.insert(group, specifiers)
+    }
+
     /// Returns an iterator over the dependency groups.
     pub fn iter(&self) -> impl Iterator<Item = (&GroupName, &Vec<DependencyGroupSpecifier>)> {
         self.0.iter()
@@ -38,6 +54,29 @@ impl<'a> IntoIterator for &'a DependencyGroups {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DependencyGroups {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("DependencyGroups")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": "A table of [PEP 735](https://peps.python.org/pep-0735/) dependency groups, mapping each group to a list of requirement strings and `include-group` tables.",
+            "additionalProperties": {
+                "type": "array",
+                "items": {
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "object", "additionalProperties": { "type": "string" } }
+                    ]
+                }
+            }
+        })
+    }
+}
+
 /// Ensure that all keys in the TOML table are unique.
 impl<'de> serde::de::Deserialize<'de> for DependencyGroups {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -58,9 +97,10 @@ impl<'de> serde::de::Deserialize<'de> for DependencyGroups {
                 M: serde::de::MapAccess<'de>,
             {
                 let mut sources = BTreeMap::new();
-                while let Some((key, value)) =
-                    access.next_entry::<GroupName, Vec<DependencyGroupSpecifier>>()?
-                {
+                while let Some(key) = access.next_key::<GroupName>()? {
+                    let DependencyGroupEntries(value) = access.next_value().map_err(|err| {
+                        serde::de::Error::custom(format!("invalid dependency group `{key}`: {err}"))
+                    })?;
                     match sources.entry(key) {
                         std::collections::btree_map::Entry::Occupied(entry) => {
                             return Err(serde::de::Error::custom(format!(
@@ -81,6 +121,39 @@ impl<'de> serde::de::Deserialize<'de> for DependencyGroups {
     }
 }
 
+/// The entries of a single dependency group, which must be an array.
+struct DependencyGroupEntries(Vec<DependencyGroupSpecifier>);
+
+impl<'de> Deserialize<'de> for DependencyGroupEntries {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = DependencyGroupEntries;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an array of requirement strings and `include-group` tables")
+            }
+
+            fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+            where
+                S: serde::de::SeqAccess<'de>,
+            {
+                let mut entries = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(entry) = seq.next_element()? {
+                    entries.push(entry);
+                }
+                Ok(DependencyGroupEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
 /// A specifier item in a [PEP 735](https://peps.python.org/pep-0735/) Dependency Group.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum DependencyGroupSpecifier {
@@ -91,7 +164,7 @@ pub enum DependencyGroupSpecifier {
         /// The name of the group to include.
         include_group: GroupName,
     },
-    /// A Dependency Object Specifier.
+    /// A Dependency Object Specifier, e.g., `{ requirement = "foo", marker = "..." }`.
     Object(BTreeMap<String, String>),
 }
 
@@ -129,6 +202,12 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
                     return Err(serde::de::Error::custom("missing field `include-group`"));
                 }
 
+                // Tables with keys beyond `include-group` (e.g., a `requirement` with a `marker`)
+                // are retained as-is, to be interpreted when the groups are flattened.
+                if map_data.len() > 1 {
+                    return Ok(DependencyGroupSpecifier::Object(map_data));
+                }
+
                 if let Some(include_group) = map_data
                     .get("include-group")
                     .map(String::as_str)
@@ -357,7 +357,7 @@ impl TryFrom<DisplaySafeUrl> for ParsedArchiveUrl {
 /// or (direct archive url):
 ///   `https://github.com/foo-labs/foo/archive/master.zip#subdirectory=packages/bar`
 ///   `https://github.com/foo-labs/foo/archive/master.zip#egg=pkg&subdirectory=packages/bar`
-fn get_subdirectory(url: &Url) -> Option<PathBuf> {
+pub fn get_subdirectory(url: &Url) -> Option<PathBuf> {
     let fragment = url.fragment()?;
     let subdirectory = fragment
         .split('&')
@@ -1,3 +1,4 @@
+pub use uv_configuration::PrereleaseMode;
 use uv_distribution_types::RequirementSource;
 use uv_normalize::PackageName;
 use uv_pep440::Operator;
@@ -5,42 +6,6 @@ use uv_pep440::Operator;
 use crate::resolver::ForkSet;
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[serde(deny_unknown_fields, rename_all = "kebab-case")]
-#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-pub enum PrereleaseMode {
-    /// Disallow all pre-release versions.
-    Disallow,
-
-    /// Allow all pre-release versions.
-    Allow,
-
-    /// Allow pre-release versions if all versions of a package are pre-release.
-    IfNecessary,
-
-    /// Allow pre-release versions for first-party packages with explicit pre-release markers in
-    /// their version requirements.
-    Explicit,
-
-    /// Allow pre-release versions if all versions of a package are pre-release, or if the package
-    /// has an explicit pre-release marker in its version requirements.
-    #[default]
-    IfNecessaryOrExplicit,
-}
-
-impl std::fmt::Display for PrereleaseMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Disallow => write!(f, "disallow"),
-            Self::Allow => write!(f, "allow"),
-            Self::IfNecessary => write!(f, "if-necessary"),
-            Self::Explicit => write!(f, "explicit"),
-            Self::IfNecessaryOrExplicit => write!(f, "if-necessary-or-explicit"),
-        }
-    }
-}
-
 /// Like [`PrereleaseMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
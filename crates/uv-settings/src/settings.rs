@@ -139,6 +139,15 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub sources: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub sources_file: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub group_aliases: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub environment_overlays: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub dev_dependencies: Option<serde::de::IgnoredAny>,
 
@@ -1980,6 +1989,9 @@ pub struct OptionsWire {
     conflicts: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
+    sources_file: Option<serde::de::IgnoredAny>,
+    group_aliases: Option<serde::de::IgnoredAny>,
+    environment_overlays: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
@@ -2049,6 +2061,9 @@ impl From<OptionsWire> for Options {
             check_url,
             workspace,
             sources,
+            sources_file,
+            group_aliases,
+            environment_overlays,
             default_groups,
             dependency_groups,
             extra_build_dependencies,
@@ -2129,6 +2144,9 @@ impl From<OptionsWire> for Options {
             add: AddOptions { add_bounds: bounds },
             workspace,
             sources,
+            sources_file,
+            group_aliases,
+            environment_overlays,
             dev_dependencies,
             default_groups,
             dependency_groups,
@@ -1,17 +1,23 @@
 use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use std::path::Path;
 use std::str::FromStr;
-use std::{collections::BTreeMap, path::Path};
 
+use itertools::Itertools;
+use serde::Deserialize;
 use thiserror::Error;
 use tracing::error;
+use version_ranges::Ranges;
 
 use uv_distribution_types::RequiresPython;
 use uv_fs::Simplified;
-use uv_normalize::{DEV_DEPENDENCIES, GroupName};
+use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
-use uv_pep508::Pep508Error;
+use uv_pep508::{MarkerTree, Pep508Error, VersionOrUrl};
 use uv_pypi_types::{DependencyGroupSpecifier, VerbatimParsedUrl};
 
+use crate::WorkspaceMember;
 use crate::pyproject::{DependencyGroupSettings, PyProjectToml, ToolUvDependencyGroups};
 
 /// PEP 735 dependency groups, with any `include-group` entries resolved.
@@ -22,15 +28,42 @@ pub struct FlatDependencyGroups(BTreeMap<GroupName, FlatDependencyGroup>);
 pub struct FlatDependencyGroup {
     pub requirements: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
     pub requires_python: Option<VersionSpecifiers>,
+    /// The requirements that only apply when other groups are active, keyed by the set of groups
+    /// that must all be active, per `{ include-group = "...", when-active = "..." }` entries.
+    pub when_active: BTreeMap<BTreeSet<GroupName>, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+}
+
+/// How to merge the legacy `tool.uv.dev-dependencies` into the `dev` group, for packages that
+/// appear in both.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MergeMode {
+    /// Retain both requirements, leaving it to the resolver to satisfy them together.
+    #[default]
+    AppendBoth,
+    /// Merge the two requirements by intersecting their version specifiers, erroring if the
+    /// intersection is empty.
+    ///
+    /// Requirements with differing markers, or on a URL, can't be merged, and are retained as-is.
+    TightenSpecifiers,
+    /// Reject any package that appears in both.
+    Error,
 }
 
 impl FlatDependencyGroups {
     /// Gather and flatten all the dependency-groups defined in the given pyproject.toml
     ///
+    /// Any `{ extends = "<project>", group = "<group>" }` entries are resolved against the given
+    /// workspace members.
+    ///
+    /// The legacy `tool.uv.dev-dependencies` are merged into the `dev` group per the given
+    /// [`MergeMode`].
+    ///
     /// The path is only used in diagnostics.
     pub fn from_pyproject_toml(
         path: &Path,
         pyproject_toml: &PyProjectToml,
+        members: &BTreeMap<PackageName, WorkspaceMember>,
+        merge_mode: MergeMode,
     ) -> Result<Self, DependencyGroupError> {
         // First, collect `tool.uv.dev_dependencies`
         let dev_dependencies = pyproject_toml
@@ -55,20 +88,17 @@ impl FlatDependencyGroups {
             .and_then(|uv| uv.dependency_groups.as_ref())
             .unwrap_or(&empty_settings);
 
+        let into_error = |error: DependencyGroupErrorInner| {
+            DependencyGroupError::new(path, pyproject_toml, error)
+        };
+
         // Flatten the dependency groups.
         let mut dependency_groups = FlatDependencyGroups::from_dependency_groups(
             &dependency_groups,
             group_settings.inner(),
+            Some(members),
         )
-        .map_err(|err| DependencyGroupError {
-            package: pyproject_toml
-                .project
-                .as_ref()
-                .map(|project| project.name.to_string())
-                .unwrap_or_default(),
-            path: path.user_display().to_string(),
-            error: err.with_dev_dependencies(dev_dependencies),
-        })?;
+        .map_err(|err| into_error(err.with_dev_dependencies(dev_dependencies)))?;
 
         // Add the `dev` group, if the legacy `dev-dependencies` is defined.
         //
@@ -78,26 +108,231 @@ impl FlatDependencyGroups {
         // way, and letting things include-group a group that isn't defined would be a
         // mess for other python tools.
         if let Some(dev_dependencies) = dev_dependencies {
-            dependency_groups
+            let dev = dependency_groups
                 .entry(DEV_DEPENDENCIES.clone())
-                .or_insert_with(FlatDependencyGroup::default)
-                .requirements
-                .extend(dev_dependencies.clone());
+                .or_insert_with(FlatDependencyGroup::default);
+            Self::merge_dev_dependencies(&mut dev.requirements, dev_dependencies, merge_mode)
+                .map_err(into_error)?;
         }
 
         Ok(dependency_groups)
     }
 
+    /// Merge the legacy `tool.uv.dev-dependencies` into the requirements of the `dev` group.
+    fn merge_dev_dependencies(
+        requirements: &mut Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+        dev_dependencies: &[uv_pep508::Requirement<VerbatimParsedUrl>],
+        merge_mode: MergeMode,
+    ) -> Result<(), DependencyGroupErrorInner> {
+        /// Return the version specifiers of a requirement, or `None` for a URL requirement.
+        fn specifiers(
+            requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
+        ) -> Option<VersionSpecifiers> {
+            match &requirement.version_or_url {
+                None => Some(VersionSpecifiers::empty()),
+                Some(VersionOrUrl::VersionSpecifier(specifiers)) => Some(specifiers.clone()),
+                Some(VersionOrUrl::Url(_)) => None,
+            }
+        }
+
+        // Only compare against the `dev` group's own requirements, and not against the
+        // dev-dependencies appended below, which may repeat a package with disjoint markers.
+        let declared = requirements.len();
+        for dev_dependency in dev_dependencies {
+            let existing = requirements[..declared]
+                .iter()
+                .position(|requirement| requirement.name == dev_dependency.name);
+            let Some(existing) = existing else {
+                requirements.push(dev_dependency.clone());
+                continue;
+            };
+
+            match merge_mode {
+                MergeMode::AppendBoth => {
+                    requirements.push(dev_dependency.clone());
+                }
+                MergeMode::Error => {
+                    return Err(DependencyGroupErrorInner::DuplicateDevDependency(
+                        dev_dependency.name.clone(),
+                    ));
+                }
+                MergeMode::TightenSpecifiers => {
+                    let existing = &mut requirements[existing];
+                    let (Some(left), Some(right)) =
+                        (specifiers(existing), specifiers(dev_dependency))
+                    else {
+                        requirements.push(dev_dependency.clone());
+                        continue;
+                    };
+                    if existing.marker != dev_dependency.marker {
+                        requirements.push(dev_dependency.clone());
+                        continue;
+                    }
+
+                    let intersection = left
+                        .iter()
+                        .chain(right.iter())
+                        .unique()
+                        .cloned()
+                        .collect::<VersionSpecifiers>();
+                    if Ranges::from(intersection.clone()).is_empty() {
+                        return Err(DependencyGroupErrorInner::DevDependencyConflict(
+                            dev_dependency.name.clone(),
+                            left,
+                            right,
+                        ));
+                    }
+
+                    existing.version_or_url = if intersection.is_empty() {
+                        None
+                    } else {
+                        Some(VersionOrUrl::VersionSpecifier(intersection))
+                    };
+                    existing.extras = existing
+                        .extras
+                        .iter()
+                        .chain(&dev_dependency.extras)
+                        .unique()
+                        .cloned()
+                        .collect();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Resolve the dependency groups (which may contain references to other groups) into concrete
     /// lists of requirements.
+    ///
+    /// If `members` is `None`, `extends` entries are not permitted. This is the case when
+    /// flattening a group that is itself being extended, which avoids cycles across projects.
     fn from_dependency_groups(
         groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
         settings: &BTreeMap<GroupName, DependencyGroupSettings>,
+        members: Option<&BTreeMap<PackageName, WorkspaceMember>>,
     ) -> Result<Self, DependencyGroupErrorInner> {
+        /// Flatten the group `extended` of the workspace member `project`.
+        fn resolve_extends(
+            name: &GroupName,
+            project: &str,
+            extended: &str,
+            members: Option<&BTreeMap<PackageName, WorkspaceMember>>,
+        ) -> Result<FlatDependencyGroup, DependencyGroupErrorInner> {
+            let Some(members) = members else {
+                return Err(DependencyGroupErrorInner::NestedExtends(name.clone()));
+            };
+            let unknown = || {
+                DependencyGroupErrorInner::ExtendsUnknownProjectGroup(
+                    name.clone(),
+                    project.to_string(),
+                    extended.to_string(),
+                )
+            };
+            let member = PackageName::from_str(project)
+                .ok()
+                .and_then(|project| members.get(&project))
+                .ok_or_else(unknown)?;
+            let extended = GroupName::from_str(extended).map_err(|_| unknown())?;
+
+            // Attribute any errors in the extended member's groups to its own `pyproject.toml`.
+            let pyproject_toml = member.pyproject_toml();
+            let into_error = |error: DependencyGroupErrorInner| {
+                DependencyGroupErrorInner::ExtendedGroupError(
+                    name.clone(),
+                    project.to_string(),
+                    extended.to_string(),
+                    Box::new(DependencyGroupError::new(
+                        &member.root().join("pyproject.toml"),
+                        pyproject_toml,
+                        error,
+                    )),
+                )
+            };
+            let dependency_groups = pyproject_toml
+                .dependency_groups
+                .iter()
+                .flatten()
+                .collect::<BTreeMap<_, _>>();
+            let empty_settings = ToolUvDependencyGroups::default();
+            let group_settings = pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dependency_groups.as_ref())
+                .unwrap_or(&empty_settings);
+            let mut flat = FlatDependencyGroups::from_dependency_groups(
+                &dependency_groups,
+                group_settings.inner(),
+                None,
+            )
+            .map_err(into_error)?;
+            let flat = flat.0.remove(&extended).ok_or_else(unknown)?;
+
+            // The conditional requirements refer to the groups of the extended member, which
+            // can't be activated from this project.
+            if !flat.when_active.is_empty() {
+                return Err(DependencyGroupErrorInner::ExtendsConditionalGroup(
+                    name.clone(),
+                    project.to_string(),
+                    extended.to_string(),
+                ));
+            }
+            Ok(flat)
+        }
+
+        /// Merge the conditional requirements of an included group into those of the including
+        /// group, additionally requiring the given group to be active, if any.
+        fn extend_when_active(
+            when_active: &mut BTreeMap<
+                BTreeSet<GroupName>,
+                Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+            >,
+            included: &FlatDependencyGroup,
+            active: Option<&GroupName>,
+            marker: MarkerTree,
+        ) {
+            for (groups, requirements) in &included.when_active {
+                let groups = groups.iter().chain(active).cloned().collect();
+                when_active
+                    .entry(groups)
+                    .or_default()
+                    .extend(requirements.iter().cloned().map(|mut requirement| {
+                        requirement.marker.and(marker);
+                        requirement
+                    }));
+            }
+        }
+
+        /// Collect the groups that the given specifiers include unconditionally, either directly
+        /// or via another unconditionally included group.
+        fn unconditional_includes<'data>(
+            groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
+            specifiers: &'data [DependencyGroupSpecifier],
+        ) -> BTreeSet<&'data GroupName> {
+            let mut unconditional = BTreeSet::new();
+            let mut stack = vec![specifiers];
+            while let Some(specifiers) = stack.pop() {
+                for specifier in specifiers {
+                    let DependencyGroupSpecifier::IncludeGroup { include_group } = specifier else {
+                        continue;
+                    };
+                    if !unconditional.insert(include_group) {
+                        continue;
+                    }
+                    if let Some(specifiers) = groups.get(include_group) {
+                        stack.push(specifiers);
+                    }
+                }
+            }
+            unconditional
+        }
+
         fn resolve_group<'data>(
             resolved: &mut BTreeMap<GroupName, FlatDependencyGroup>,
             groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
             settings: &BTreeMap<GroupName, DependencyGroupSettings>,
+            members: Option<&BTreeMap<PackageName, WorkspaceMember>>,
             name: &'data GroupName,
             parents: &mut Vec<&'data GroupName>,
         ) -> Result<(), DependencyGroupErrorInner> {
@@ -128,7 +363,12 @@ impl FlatDependencyGroups {
 
             parents.push(name);
             let mut requirements = Vec::with_capacity(specifiers.len());
+            let mut when_active = BTreeMap::new();
             let mut requires_python_intersection = VersionSpecifiers::empty();
+
+            // If a group is included both unconditionally and under a marker, the unconditional
+            // inclusion wins, so collect the groups that are included unconditionally.
+            let unconditional = unconditional_includes(groups, specifiers);
             for specifier in *specifiers {
                 match specifier {
                     DependencyGroupSpecifier::Requirement(requirement) => {
@@ -144,9 +384,10 @@ impl FlatDependencyGroups {
                         }
                     }
                     DependencyGroupSpecifier::IncludeGroup { include_group } => {
-                        resolve_group(resolved, groups, settings, include_group, parents)?;
+                        resolve_group(resolved, groups, settings, members, include_group, parents)?;
                         if let Some(included) = resolved.get(include_group) {
                             requirements.extend(included.requirements.iter().cloned());
+                            extend_when_active(&mut when_active, included, None, MarkerTree::TRUE);
 
                             // Intersect the requires-python for this group with the included group's
                             requires_python_intersection = requires_python_intersection
@@ -155,20 +396,163 @@ impl FlatDependencyGroups {
                                 .collect();
                         }
                     }
-                    DependencyGroupSpecifier::Object(map) => {
-                        return Err(
+                    DependencyGroupSpecifier::Object(map)
+                        if map.len() == 2
+                            && map.contains_key("include-group")
+                            && map.contains_key("marker") =>
+                    {
+                        // Support `{ include-group = "...", marker = "..." }` entries, which only
+                        // include the group where the marker applies. The marker is conjoined with
+                        // any markers on the included requirements, unless the group is also
+                        // included unconditionally.
+                        let include_group =
+                            GroupName::from_str(&map["include-group"]).map_err(|_| {
+                                DependencyGroupErrorInner::DependencyObjectSpecifierNotSupported(
+                                    name.clone(),
+                                    map.clone(),
+                                )
+                            })?;
+                        let Some((&include_group, _)) = groups.get_key_value(&include_group) else {
+                            return Err(DependencyGroupErrorInner::GroupNotFound(
+                                include_group,
+                                name.clone(),
+                            ));
+                        };
+                        let marker = MarkerTree::parse_str::<VerbatimParsedUrl>(&map["marker"])
+                            .map_err(|err| {
+                                DependencyGroupErrorInner::GroupParseError(
+                                    name.clone(),
+                                    map["marker"].clone(),
+                                    Box::new(err),
+                                )
+                            })?;
+                        if unconditional.contains(include_group) {
+                            continue;
+                        }
+                        resolve_group(resolved, groups, settings, members, include_group, parents)?;
+                        if let Some(included) = resolved.get(include_group) {
+                            requirements.extend(included.requirements.iter().cloned().map(
+                                |mut requirement| {
+                                    requirement.marker.and(marker);
+                                    requirement
+                                },
+                            ));
+                            extend_when_active(&mut when_active, included, None, marker);
+                            requires_python_intersection = requires_python_intersection
+                                .into_iter()
+                                .chain(included.requires_python.clone().into_iter().flatten())
+                                .collect();
+                        }
+                    }
+                    DependencyGroupSpecifier::Object(map)
+                        if map.len() == 2
+                            && map.contains_key("include-group")
+                            && map.contains_key("when-active") =>
+                    {
+                        // Support `{ include-group = "...", when-active = "..." }` entries, which
+                        // only include the group if another group is also active. Since flattening
+                        // doesn't depend on the active groups, the requirements are retained
+                        // separately, and don't contribute to the group's `requires-python`.
+                        let invalid = || {
                             DependencyGroupErrorInner::DependencyObjectSpecifierNotSupported(
                                 name.clone(),
                                 map.clone(),
-                            ),
-                        );
+                            )
+                        };
+                        let include_group =
+                            GroupName::from_str(&map["include-group"]).map_err(|_| invalid())?;
+                        let active =
+                            GroupName::from_str(&map["when-active"]).map_err(|_| invalid())?;
+                        let Some((&include_group, _)) = groups.get_key_value(&include_group) else {
+                            return Err(DependencyGroupErrorInner::GroupNotFound(
+                                include_group,
+                                name.clone(),
+                            ));
+                        };
+                        if !groups.contains_key(&active) {
+                            return Err(DependencyGroupErrorInner::WhenActiveGroupNotFound(
+                                name.clone(),
+                                active,
+                            ));
+                        }
+                        resolve_group(resolved, groups, settings, members, include_group, parents)?;
+                        if let Some(included) = resolved.get(include_group) {
+                            when_active
+                                .entry(BTreeSet::from([active.clone()]))
+                                .or_default()
+                                .extend(included.requirements.iter().cloned());
+                            extend_when_active(
+                                &mut when_active,
+                                included,
+                                Some(&active),
+                                MarkerTree::TRUE,
+                            );
+                        }
+                    }
+                    DependencyGroupSpecifier::Object(map)
+                        if map.len() == 2
+                            && map.contains_key("extends")
+                            && map.contains_key("group") =>
+                    {
+                        // Support `{ extends = "<project>", group = "<group>" }` entries, which
+                        // include a group from another workspace member.
+                        let extended =
+                            resolve_extends(name, &map["extends"], &map["group"], members)?;
+                        requirements.extend(extended.requirements);
+                        requires_python_intersection = requires_python_intersection
+                            .into_iter()
+                            .chain(extended.requires_python.into_iter().flatten())
+                            .collect();
+                    }
+                    DependencyGroupSpecifier::Object(map) => {
+                        if map.contains_key("include-group") && map.contains_key("requirement") {
+                            return Err(DependencyGroupErrorInner::AmbiguousGroupEntry(
+                                name.clone(),
+                            ));
+                        }
+
+                        // Support `{ requirement = "...", marker = "..." }` entries, which attach
+                        // a marker without embedding it in the PEP 508 string.
+                        let Some(requirement) = map.get("requirement").filter(|_| {
+                            map.keys()
+                                .all(|key| matches!(key.as_str(), "requirement" | "marker"))
+                        }) else {
+                            return Err(
+                                DependencyGroupErrorInner::DependencyObjectSpecifierNotSupported(
+                                    name.clone(),
+                                    map.clone(),
+                                ),
+                            );
+                        };
+                        let mut requirement =
+                            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                                .map_err(|err| {
+                                    DependencyGroupErrorInner::GroupParseError(
+                                        name.clone(),
+                                        requirement.clone(),
+                                        Box::new(err),
+                                    )
+                                })?;
+                        if let Some(marker) = map.get("marker") {
+                            let marker = MarkerTree::parse_str::<VerbatimParsedUrl>(marker)
+                                .map_err(|err| {
+                                    DependencyGroupErrorInner::GroupParseError(
+                                        name.clone(),
+                                        marker.clone(),
+                                        Box::new(err),
+                                    )
+                                })?;
+                            requirement.marker.and(marker);
+                        }
+                        requirements.push(requirement);
                     }
                 }
             }
 
             let empty_settings = DependencyGroupSettings::default();
-            let DependencyGroupSettings { requires_python } =
-                settings.get(name).unwrap_or(&empty_settings);
+            let DependencyGroupSettings {
+                requires_python, ..
+            } = settings.get(name).unwrap_or(&empty_settings);
             if let Some(requires_python) = requires_python {
                 // Intersect the requires-python for this group to get the final requires-python
                 // that will be used by interpreter discovery and checking.
@@ -180,7 +564,10 @@ impl FlatDependencyGroups {
                 // Add the group requires-python as a marker to each requirement
                 // We don't use `requires_python_intersection` because each `include-group`
                 // should already have its markers applied to these.
-                for requirement in &mut requirements {
+                for requirement in requirements
+                    .iter_mut()
+                    .chain(when_active.values_mut().flatten())
+                {
                     let extra_markers =
                         RequiresPython::from_specifiers(requires_python).to_marker_tree();
                     requirement.marker.and(extra_markers);
@@ -198,6 +585,7 @@ impl FlatDependencyGroups {
                     } else {
                         Some(requires_python_intersection)
                     },
+                    when_active,
                 },
             );
             Ok(())
@@ -215,7 +603,7 @@ impl FlatDependencyGroups {
         let mut resolved = BTreeMap::new();
         for name in groups.keys() {
             let mut parents = Vec::new();
-            resolve_group(&mut resolved, groups, settings, name, &mut parents)?;
+            resolve_group(&mut resolved, groups, settings, members, name, &mut parents)?;
         }
         Ok(Self(resolved))
     }
@@ -230,6 +618,11 @@ impl FlatDependencyGroups {
         self.0.entry(group)
     }
 
+    /// Return an iterator over the dependency groups.
+    pub fn iter(&self) -> impl Iterator<Item = (&GroupName, &FlatDependencyGroup)> {
+        self.0.iter()
+    }
+
     /// Consume the [`FlatDependencyGroups`] and return the inner map.
     pub fn into_inner(self) -> BTreeMap<GroupName, FlatDependencyGroup> {
         self.0
@@ -264,10 +657,40 @@ impl IntoIterator for FlatDependencyGroups {
 pub struct DependencyGroupError {
     package: String,
     path: String,
+    span: Option<Range<usize>>,
     #[source]
     error: DependencyGroupErrorInner,
 }
 
+impl DependencyGroupError {
+    /// Attribute the error to the given `pyproject.toml`, whose path is only used in diagnostics.
+    fn new(path: &Path, pyproject_toml: &PyProjectToml, error: DependencyGroupErrorInner) -> Self {
+        Self {
+            package: pyproject_toml
+                .project
+                .as_ref()
+                .map(|project| project.name.to_string())
+                .unwrap_or_default(),
+            path: path.user_display().to_string(),
+            span: error.span(&pyproject_toml.raw),
+            error,
+        }
+    }
+
+    /// Return the byte range within the `pyproject.toml` to which the error refers, if known.
+    ///
+    /// The span covers the malformed entry where it can be identified, and the enclosing group
+    /// otherwise, e.g., to allow editors to underline the problem.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Return the underlying error, e.g., to distinguish the kinds of malformed groups.
+    pub fn inner(&self) -> &DependencyGroupErrorInner {
+        &self.error
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DependencyGroupErrorInner {
     #[error("Failed to parse entry in group `{0}`: `{1}`")]
@@ -278,6 +701,8 @@ pub enum DependencyGroupErrorInner {
     ),
     #[error("Failed to find group `{0}` included by `{1}`")]
     GroupNotFound(GroupName, GroupName),
+    #[error("Group `{0}` includes a group when `{1}` is active, but no such group was found")]
+    WhenActiveGroupNotFound(GroupName, GroupName),
     #[error(
         "Group `{0}` includes the `dev` group (`include = \"dev\"`), but only `tool.uv.dev-dependencies` was found. To reference the `dev` group via an `include`, remove the `tool.uv.dev-dependencies` section and add any development dependencies to the `dev` entry in the `[dependency-groups]` table instead."
     )]
@@ -286,6 +711,37 @@ pub enum DependencyGroupErrorInner {
     DependencyGroupCycle(Cycle),
     #[error("Group `{0}` contains an unknown dependency object specifier: {1:?}")]
     DependencyObjectSpecifierNotSupported(GroupName, BTreeMap<String, String>),
+    #[error(
+        "Group `{0}` contains an entry that specifies both `include-group` and `requirement`; each entry must specify exactly one"
+    )]
+    AmbiguousGroupEntry(GroupName),
+    #[error(
+        "Group `{0}` extends group `{2}` of project `{1}`, but no such group was found in the workspace members"
+    )]
+    ExtendsUnknownProjectGroup(GroupName, String, String),
+    #[error(
+        "Group `{0}` uses `extends`, which is not supported in a group that is itself extended by another project"
+    )]
+    NestedExtends(GroupName),
+    #[error(
+        "Group `{0}` extends group `{2}` of project `{1}`, which has malformed dependency groups"
+    )]
+    ExtendedGroupError(
+        GroupName,
+        String,
+        String,
+        #[source] Box<DependencyGroupError>,
+    ),
+    #[error(
+        "Group `{0}` extends group `{2}` of project `{1}`, which includes groups with `when-active`; only groups without conditional includes can be extended"
+    )]
+    ExtendsConditionalGroup(GroupName, String, String),
+    #[error(
+        "`{0}` is declared in both `tool.uv.dev-dependencies` and the `dev` group, with incompatible version specifiers (`{1}` and `{2}`)"
+    )]
+    DevDependencyConflict(PackageName, VersionSpecifiers, VersionSpecifiers),
+    #[error("`{0}` is declared in both `tool.uv.dev-dependencies` and the `dev` group")]
+    DuplicateDevDependency(PackageName),
     #[error("Failed to find group `{0}` specified in `[tool.uv.dependency-groups]`")]
     SettingsGroupNotFound(GroupName),
     #[error(
@@ -315,6 +771,52 @@ impl DependencyGroupErrorInner {
             _ => self,
         }
     }
+
+    /// Locate the byte range within the `pyproject.toml` contents to which the error refers.
+    ///
+    /// Returns `None` for errors that aren't attributable to a single group, such as cycles.
+    fn span(&self, contents: &str) -> Option<Range<usize>> {
+        let (group, entry) = match self {
+            Self::GroupParseError(group, entry, _) => (group, Some(entry.as_str())),
+            Self::GroupNotFound(_, group)
+            | Self::DevGroupInclude(group)
+            | Self::DependencyObjectSpecifierNotSupported(group, _)
+            | Self::AmbiguousGroupEntry(group)
+            | Self::ExtendsUnknownProjectGroup(group, ..)
+            | Self::ExtendedGroupError(group, ..)
+            | Self::ExtendsConditionalGroup(group, ..)
+            | Self::NestedExtends(group)
+            | Self::WhenActiveGroupNotFound(group, _) => (group, None),
+            Self::DependencyGroupCycle(_)
+            | Self::DevDependencyConflict(..)
+            | Self::DuplicateDevDependency(_)
+            | Self::SettingsGroupNotFound(_)
+            | Self::SettingsDevGroupInclude => return None,
+        };
+
+        // Re-parse the `dependency-groups` table, retaining the span of each group and entry.
+        let SpannedDependencyGroups { dependency_groups } = toml::from_str(contents).ok()?;
+        let entries = dependency_groups?
+            .into_iter()
+            .find(|(name, _)| GroupName::from_str(name).is_ok_and(|name| name == *group))
+            .map(|(_, entries)| entries)?;
+        entry
+            .and_then(|entry| {
+                entries
+                    .get_ref()
+                    .iter()
+                    .find(|value| value.get_ref().as_str() == Some(entry))
+                    .map(toml::Spanned::span)
+            })
+            .or_else(|| Some(entries.span()))
+    }
+}
+
+/// The `dependency-groups` table of a `pyproject.toml`, with the span of each group and entry.
+#[derive(Deserialize)]
+struct SpannedDependencyGroups {
+    #[serde(rename = "dependency-groups")]
+    dependency_groups: Option<BTreeMap<String, toml::Spanned<Vec<toml::Spanned<toml::Value>>>>>,
 }
 
 /// A cycle in the `dependency-groups` table.
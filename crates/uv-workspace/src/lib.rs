@@ -1,6 +1,6 @@
 pub use workspace::{
-    DiscoveryOptions, MemberDiscovery, ProjectWorkspace, RequiresPythonSources, VirtualProject,
-    Workspace, WorkspaceCache, WorkspaceError, WorkspaceMember,
+    DiscoveryOptions, MemberDecision, MemberDiscovery, ProjectWorkspace, RequiresPythonSources,
+    VirtualProject, Workspace, WorkspaceCache, WorkspaceError, WorkspaceMember,
 };
 
 pub mod dependency_groups;
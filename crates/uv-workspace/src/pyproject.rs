@@ -21,6 +21,7 @@ use serde::de::{IntoDeserializer, SeqAccess};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 use uv_build_backend::BuildBackendSettings;
+use uv_configuration::{PrereleaseMode, RequiredVersion, SourceStrategy};
 use uv_distribution_types::{Index, IndexName, RequirementSource};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
@@ -324,6 +325,20 @@ pub struct ToolUv {
     )]
     pub sources: Option<ToolUvSources>,
 
+    /// A file from which to load additional sources, relative to the project root.
+    ///
+    /// The file contains a table of sources in the same format as `tool.uv.sources`, keyed by
+    /// package name, and is merged with `tool.uv.sources`. If a package has sources in both, the
+    /// sources in `tool.uv.sources` take precedence. The file must reside within the project root.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            sources-file = "uv.sources.toml"
+        "#
+    )]
+    pub sources_file: Option<PathBuf>,
+
     /// The indexes to use when resolving dependencies.
     ///
     /// Accepts either a repository compliant with [PEP 503](https://peps.python.org/pep-0503/)
@@ -411,7 +426,7 @@ pub struct ToolUv {
 
     /// Additional settings for `dependency-groups`.
     ///
-    /// Currently this can only be used to add `requires-python` constraints
+    /// For example, this can be used to add `requires-python` constraints
     /// to dependency groups (typically to inform uv that your dev tooling
     /// has a higher python requirement than your actual project).
     ///
@@ -427,6 +442,42 @@ pub struct ToolUv {
     )]
     pub dependency_groups: Option<ToolUvDependencyGroups>,
 
+    /// Alternative names for `dependency-groups`, mapping each alias to the group it refers to.
+    ///
+    /// A request for an alias (e.g., `--group testing`) resolves to the target group, which
+    /// allows a group to be renamed without breaking existing references to the old name. An
+    /// alias may refer to another alias, but must ultimately refer to a defined group.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            [tool.uv.group-aliases]
+            testing = "test"
+        "#
+    )]
+    pub group_aliases: Option<BTreeMap<GroupName, GroupName>>,
+
+    /// Environment-specific overlays on the project's `dependency-groups`, keyed by environment
+    /// name (e.g., `ci`).
+    ///
+    /// When an environment is selected, its `dependency-groups` are merged on top of the
+    /// project's: the overlay's entries are appended to the group of the same name, and supersede
+    /// any requirements in the group on the same packages. An overlay may only introduce a group
+    /// that the project doesn't define if the group is listed in its `new-groups`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.environment-overlays.ci]
+            new-groups = ["ci"]
+
+            [tool.uv.environment-overlays.ci.dependency-groups]
+            test = ["pytest-xdist"]
+            ci = ["tox"]
+        "#
+    )]
+    pub environment_overlays: Option<BTreeMap<String, EnvironmentOverlay>>,
+
     /// Additional build dependencies for packages.
     ///
     /// This allows extending the PEP 517 build environment for the project's dependencies with
@@ -753,6 +804,36 @@ pub struct DependencyGroupSettings {
     /// Version of python to require when installing this group
     #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub requires_python: Option<VersionSpecifiers>,
+    /// Constraint files to apply when installing this group, relative to the project root
+    pub constraints: Option<Vec<PathBuf>>,
+    /// Whether this group must be requested by name, such that it's never installed implicitly
+    /// (e.g., via `default-groups` or `--all-groups`)
+    pub explicit: Option<bool>,
+    /// Whether to ignore `tool.uv.sources` when lowering this group, such that its requirements are
+    /// resolved from the registry
+    pub no_sources: Option<bool>,
+    /// The strategy to use when considering pre-release versions for this group, in lieu of the
+    /// project-wide `prerelease` setting
+    pub prerelease: Option<PrereleaseMode>,
+    /// Whether this group is internal to the project, such that it may be included by other
+    /// groups, but shouldn't be requested directly (e.g., via `--group`)
+    pub private: Option<bool>,
+    /// Whether to apply `tool.uv.sources` and `tool.uv.index` when lowering this group, in lieu
+    /// of the project-wide strategy (e.g., to enable sources for a group despite `--no-sources`);
+    /// `disabled` is equivalent to `no-sources = true`, with which it must agree if both are set
+    pub sources: Option<SourceStrategy>,
+}
+
+/// An environment-specific overlay on the project's `dependency-groups`.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct EnvironmentOverlay {
+    /// The entries to merge into the project's dependency groups, keyed by group name
+    pub dependency_groups: Option<DependencyGroups>,
+    /// The groups that the overlay introduces, which need not be defined by the project
+    pub new_groups: Option<Vec<GroupName>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
@@ -1053,6 +1134,8 @@ pub enum Source {
         marker: MarkerTree,
         extra: Option<ExtraName>,
         group: Option<GroupName>,
+        /// The versions of uv that support this source, e.g., `>=0.5`.
+        requires_uv: Option<RequiredVersion>,
     },
     /// A remote `http://` or `https://` URL, either a wheel (`.whl`) or a source distribution
     /// (`.zip`, `.tar.gz`).
@@ -1074,6 +1157,8 @@ pub enum Source {
         marker: MarkerTree,
         extra: Option<ExtraName>,
         group: Option<GroupName>,
+        /// The versions of uv that support this source, e.g., `>=0.5`.
+        requires_uv: Option<RequiredVersion>,
     },
     /// The path to a dependency, either a wheel (a `.whl` file), source distribution (a `.zip` or
     /// `.tar.gz` file), or source tree (i.e., a directory containing a `pyproject.toml` or
@@ -1097,6 +1182,8 @@ pub enum Source {
         marker: MarkerTree,
         extra: Option<ExtraName>,
         group: Option<GroupName>,
+        /// The versions of uv that support this source, e.g., `>=0.5`.
+        requires_uv: Option<RequiredVersion>,
     },
     /// A dependency pinned to a specific index, e.g., `torch` after setting `torch` to `https://download.pytorch.org/whl/cu118`.
     Registry {
@@ -1109,6 +1196,8 @@ pub enum Source {
         marker: MarkerTree,
         extra: Option<ExtraName>,
         group: Option<GroupName>,
+        /// The versions of uv that support this source, e.g., `>=0.5`.
+        requires_uv: Option<RequiredVersion>,
     },
     /// A dependency on another package in the workspace.
     Workspace {
@@ -1123,6 +1212,8 @@ pub enum Source {
         marker: MarkerTree,
         extra: Option<ExtraName>,
         group: Option<GroupName>,
+        /// The versions of uv that support this source, e.g., `>=0.5`.
+        requires_uv: Option<RequiredVersion>,
     },
 }
 
@@ -1155,6 +1246,7 @@ impl<'de> Deserialize<'de> for Source {
             marker: MarkerTree,
             extra: Option<ExtraName>,
             group: Option<GroupName>,
+            requires_uv: Option<RequiredVersion>,
         }
 
         // Attempt to deserialize as `CatchAll`.
@@ -1173,6 +1265,7 @@ impl<'de> Deserialize<'de> for Source {
             marker,
             extra,
             group,
+            requires_uv,
         } = CatchAll::deserialize(deserializer)?;
 
         // If both `extra` and `group` are set, return an error.
@@ -1244,6 +1337,7 @@ impl<'de> Deserialize<'de> for Source {
                 marker,
                 extra,
                 group,
+                requires_uv,
             });
         }
 
@@ -1301,6 +1395,7 @@ impl<'de> Deserialize<'de> for Source {
                 marker,
                 extra,
                 group,
+                requires_uv,
             });
         }
 
@@ -1356,6 +1451,7 @@ impl<'de> Deserialize<'de> for Source {
                 marker,
                 extra,
                 group,
+                requires_uv,
             });
         }
 
@@ -1412,6 +1508,7 @@ impl<'de> Deserialize<'de> for Source {
                 marker,
                 extra,
                 group,
+                requires_uv,
             });
         }
 
@@ -1468,6 +1565,7 @@ impl<'de> Deserialize<'de> for Source {
                 marker,
                 extra,
                 group,
+                requires_uv,
             });
         }
 
@@ -1549,6 +1647,7 @@ impl Source {
                             marker,
                             extra,
                             group,
+                            requires_uv,
                             ..
                         } = existing_source
                         {
@@ -1561,6 +1660,7 @@ impl Source {
                                 marker: *marker,
                                 extra: extra.clone(),
                                 group: group.clone(),
+                                requires_uv: requires_uv.clone(),
                             }));
                         }
                     }
@@ -1600,6 +1700,7 @@ impl Source {
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
+                        requires_uv: None,
                     }))
                 }
                 RequirementSource::Url { .. } => {
@@ -1625,6 +1726,7 @@ impl Source {
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
+                        requires_uv: None,
                     }
                 } else {
                     return Ok(None);
@@ -1643,6 +1745,7 @@ impl Source {
                 marker: MarkerTree::TRUE,
                 extra: None,
                 group: None,
+                requires_uv: None,
             },
             RequirementSource::Url {
                 location,
@@ -1654,6 +1757,7 @@ impl Source {
                 marker: MarkerTree::TRUE,
                 extra: None,
                 group: None,
+                requires_uv: None,
             },
             RequirementSource::Git {
                 git, subdirectory, ..
@@ -1676,6 +1780,7 @@ impl Source {
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
+                        requires_uv: None,
                     }
                 } else {
                     Source::Git {
@@ -1687,6 +1792,7 @@ impl Source {
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
+                        requires_uv: None,
                     }
                 }
             }
@@ -1727,6 +1833,17 @@ impl Source {
             Source::Workspace { group, .. } => group.as_ref(),
         }
     }
+
+    /// Return the versions of uv that support the source, if constrained.
+    pub fn requires_uv(&self) -> Option<&RequiredVersion> {
+        match self {
+            Source::Git { requires_uv, .. } => requires_uv.as_ref(),
+            Source::Url { requires_uv, .. } => requires_uv.as_ref(),
+            Source::Path { requires_uv, .. } => requires_uv.as_ref(),
+            Source::Registry { requires_uv, .. } => requires_uv.as_ref(),
+            Source::Workspace { requires_uv, .. } => requires_uv.as_ref(),
+        }
+    }
 }
 
 /// The type of a dependency in a `pyproject.toml`.
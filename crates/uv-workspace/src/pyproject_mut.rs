@@ -6,7 +6,8 @@ use std::str::FromStr;
 use std::{fmt, iter, mem};
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TableLike, TomlError,
+    Value,
 };
 
 use uv_cache_key::CanonicalUrl;
@@ -53,6 +54,12 @@ pub enum Error {
     },
     #[error("Unknown bound king {0}")]
     UnknownBoundKind(String),
+    #[error("Dependency group `{0}` does not exist")]
+    MissingDependencyGroup(GroupName),
+    #[error("Dependency group `{0}` already exists")]
+    DuplicateDependencyGroup(GroupName),
+    #[error("`{0}` is already present in dependency group `{1}`")]
+    AlreadyPresent(PackageName, GroupName),
 }
 
 /// The result of editing an array in a TOML document.
@@ -692,6 +699,36 @@ impl PyProjectTomlMut {
         Ok(added)
     }
 
+    /// Adds a requirement to a group in `dependency-groups`, creating the group if it doesn't
+    /// exist.
+    ///
+    /// Unlike [`PyProjectTomlMut::add_dependency_group_requirement`], which updates any existing
+    /// requirement for the package, this returns an error if the group already contains a
+    /// requirement for the package (regardless of its marker).
+    pub fn add_requirement_to_group(
+        &mut self,
+        group: &GroupName,
+        req: &Requirement,
+    ) -> Result<(), Error> {
+        let existing = self
+            .doc
+            .get("dependency-groups")
+            .and_then(Item::as_table_like)
+            .and_then(|groups| {
+                let key = find_group(group, groups)?;
+                groups.get(&key)
+            })
+            .and_then(Item::as_array)
+            .is_some_and(|deps| !find_dependencies(&req.name, None, deps).is_empty());
+        if existing {
+            return Err(Error::AlreadyPresent(req.name.clone(), group.clone()));
+        }
+
+        self.add_dependency_group_requirement(group, req, None, false)?;
+
+        Ok(())
+    }
+
     /// Set the constraint for a requirement for an existing dependency.
     pub fn set_dependency_bound(
         &mut self,
@@ -1014,6 +1051,139 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Renames a dependency group, along with any references to it.
+    ///
+    /// In addition to the key in `dependency-groups`, this updates any `include-group` entries in
+    /// other groups, `group` qualifiers in `tool.uv.sources` and `tool.uv.conflicts`, and the
+    /// group's entries in `tool.uv.default-groups` and `tool.uv.dependency-groups`, and any
+    /// aliases that refer to it in `tool.uv.group-aliases`. The position of each entry and any
+    /// surrounding comments are preserved.
+    ///
+    /// Returns an error if the group doesn't exist, or if a group with the new name already does.
+    pub fn rename_dependency_group(
+        &mut self,
+        from: &GroupName,
+        to: &GroupName,
+    ) -> Result<(), Error> {
+        let dependency_groups = self
+            .doc
+            .get_mut("dependency-groups")
+            .map(|groups| {
+                groups
+                    .as_table_like_mut()
+                    .ok_or(Error::MalformedDependencies)
+            })
+            .transpose()?
+            .ok_or_else(|| Error::MissingDependencyGroup(from.clone()))?;
+
+        let Some(from_key) = find_group(from, dependency_groups) else {
+            return Err(Error::MissingDependencyGroup(from.clone()));
+        };
+        if from == to {
+            return Ok(());
+        }
+        if find_group(to, dependency_groups).is_some() {
+            return Err(Error::DuplicateDependencyGroup(to.clone()));
+        }
+        rename_key(dependency_groups, &from_key, to.as_ref());
+
+        // Update any `include-group` references in the other groups.
+        for (_, group) in dependency_groups.iter_mut() {
+            let group = group.as_array_mut().ok_or(Error::MalformedDependencies)?;
+            for entry in group.iter_mut() {
+                if let Some(entry) = entry.as_inline_table_mut() {
+                    rename_group_reference(entry, "include-group", from, to);
+                }
+            }
+        }
+
+        let Some(tool_uv) = self
+            .doc
+            .get_mut("tool")
+            .and_then(Item::as_table_like_mut)
+            .and_then(|tool| tool.get_mut("uv"))
+            .and_then(Item::as_table_like_mut)
+        else {
+            return Ok(());
+        };
+
+        // Update any `group` qualifiers in `tool.uv.sources`.
+        if let Some(sources) = tool_uv
+            .get_mut("sources")
+            .map(|sources| sources.as_table_like_mut().ok_or(Error::MalformedSources))
+            .transpose()?
+        {
+            for (_, source) in sources.iter_mut() {
+                if let Some(array) = source.as_array_mut() {
+                    for source in array.iter_mut() {
+                        if let Some(source) = source.as_inline_table_mut() {
+                            rename_group_reference(source, "group", from, to);
+                        }
+                    }
+                } else if let Some(source) = source.as_table_like_mut() {
+                    rename_group_reference(source, "group", from, to);
+                }
+            }
+        }
+
+        // Update any `group` qualifiers in `tool.uv.conflicts`.
+        if let Some(conflicts) = tool_uv.get_mut("conflicts").and_then(Item::as_array_mut) {
+            for conflict in conflicts.iter_mut() {
+                let Some(conflict) = conflict.as_array_mut() else {
+                    continue;
+                };
+                for item in conflict.iter_mut() {
+                    if let Some(item) = item.as_inline_table_mut() {
+                        rename_group_reference(item, "group", from, to);
+                    }
+                }
+            }
+        }
+
+        // Update `tool.uv.default-groups`.
+        if let Some(default_groups) = tool_uv
+            .get_mut("default-groups")
+            .and_then(Item::as_array_mut)
+        {
+            for group in default_groups.iter_mut() {
+                if group
+                    .as_str()
+                    .is_some_and(|group| GroupName::from_str(group).is_ok_and(|g| g == *from))
+                {
+                    let decor = group.decor().clone();
+                    *group = Value::from(to.as_ref());
+                    *group.decor_mut() = decor;
+                }
+            }
+        }
+
+        // Update the group's settings in `tool.uv.dependency-groups`.
+        if let Some(settings) = tool_uv
+            .get_mut("dependency-groups")
+            .and_then(Item::as_table_like_mut)
+        {
+            if let Some(key) = find_group(from, settings) {
+                rename_key(settings, &key, to.as_ref());
+            }
+        }
+
+        // Update any aliases that refer to the group in `tool.uv.group-aliases`.
+        if let Some(aliases) = tool_uv
+            .get_mut("group-aliases")
+            .and_then(Item::as_table_like_mut)
+        {
+            let keys = aliases
+                .iter()
+                .map(|(key, _)| key.to_string())
+                .collect::<Vec<_>>();
+            for key in keys {
+                rename_group_reference(aliases, &key, from, to);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns `true` if the `tool.uv.dev-dependencies` table is present.
     pub fn has_dev_dependencies(&self) -> bool {
         self.doc
@@ -1499,6 +1669,61 @@ fn find_source(name: &PackageName, sources: &Table) -> Option<String> {
     None
 }
 
+/// Returns the key of the given group in the table, if it exists.
+fn find_group(group: &GroupName, table: &dyn TableLike) -> Option<String> {
+    table.iter().find_map(|(key, _)| {
+        if GroupName::from_str(key).is_ok_and(|g| g == *group) {
+            Some(key.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Renames a key in the table, preserving its position and any surrounding comments.
+fn rename_key(table: &mut dyn TableLike, from: &str, to: &str) {
+    let keys = table
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .collect::<Vec<_>>();
+    let Some(position) = keys.iter().position(|key| key == from) else {
+        return;
+    };
+
+    // Re-insert the renamed entry, along with every entry after it, to retain the order.
+    for key in &keys[position..] {
+        let decor = table.key(key).map(|key| key.leaf_decor().clone());
+        let Some(item) = table.remove(key) else {
+            continue;
+        };
+        let key = if key == from { to } else { key.as_str() };
+        table.insert(key, item);
+        if let (Some(decor), Some(mut key)) = (decor, table.key_mut(key)) {
+            *key.leaf_decor_mut() = decor;
+        }
+    }
+}
+
+/// Updates a reference to a dependency group (e.g., `include-group = "dev"`) in the given table.
+fn rename_group_reference(
+    table: &mut dyn TableLike,
+    field: &str,
+    from: &GroupName,
+    to: &GroupName,
+) {
+    let Some(value) = table.get_mut(field).and_then(Item::as_value_mut) else {
+        return;
+    };
+    if value
+        .as_str()
+        .is_some_and(|group| GroupName::from_str(group).is_ok_and(|g| g == *from))
+    {
+        let decor = value.decor().clone();
+        *value = Value::from(to.as_ref());
+        *value.decor_mut() = decor;
+    }
+}
+
 // Add a source to `tool.uv.sources`.
 fn add_source(req: &PackageName, source: &Source, sources: &mut Table) -> Result<(), Error> {
     // Serialize as an inline table.
@@ -1645,9 +1870,12 @@ fn split_specifiers(req: &str) -> (&str, &str) {
 
 #[cfg(test)]
 mod test {
-    use super::{AddBoundsKind, split_specifiers};
+    use super::{AddBoundsKind, DependencyTarget, Error, PyProjectTomlMut, split_specifiers};
+    use insta::assert_snapshot;
     use std::str::FromStr;
+    use uv_normalize::GroupName;
     use uv_pep440::Version;
+    use uv_pep508::Requirement;
 
     #[test]
     fn split() {
@@ -1771,4 +1999,123 @@ mod test {
             assert_eq!(actual, expected, "{version}");
         }
     }
+
+    #[test]
+    fn rename_dependency_group() -> anyhow::Result<()> {
+        let mut pyproject = PyProjectTomlMut::from_toml(
+            r#"[dependency-groups]
+# Test dependencies.
+test = ["pytest"]
+dev = [{ include-group = "test" }, "ruff"]
+lint = ["ruff"]
+
+[tool.uv]
+default-groups = ["dev", "test"]
+conflicts = [[{ group = "test" }, { group = "lint" }]]
+
+[tool.uv.sources]
+pytest = { git = "https://github.com/pytest-dev/pytest", group = "test" }
+
+[tool.uv.dependency-groups]
+test = { requires-python = ">=3.12" }
+
+[tool.uv.group-aliases]
+tests = "test"
+"#,
+            DependencyTarget::PyProjectToml,
+        )?;
+
+        pyproject.rename_dependency_group(
+            &GroupName::from_str("test")?,
+            &GroupName::from_str("testing")?,
+        )?;
+        assert_snapshot!(pyproject.to_string(), @r#"
+        [dependency-groups]
+        # Test dependencies.
+        testing = ["pytest"]
+        dev = [{ include-group = "testing" }, "ruff"]
+        lint = ["ruff"]
+
+        [tool.uv]
+        default-groups = ["dev", "testing"]
+        conflicts = [[{ group = "testing" }, { group = "lint" }]]
+
+        [tool.uv.sources]
+        pytest = { git = "https://github.com/pytest-dev/pytest", group = "testing" }
+
+        [tool.uv.dependency-groups]
+        testing = { requires-python = ">=3.12" }
+
+        [tool.uv.group-aliases]
+        tests = "testing"
+        "#);
+
+        // The new name must not already be in use.
+        let err = pyproject
+            .rename_dependency_group(
+                &GroupName::from_str("testing")?,
+                &GroupName::from_str("dev")?,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::DuplicateDependencyGroup(group) if group.as_ref() == "dev"));
+
+        // And the group must exist.
+        let err = pyproject
+            .rename_dependency_group(
+                &GroupName::from_str("test")?,
+                &GroupName::from_str("tests")?,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::MissingDependencyGroup(group) if group.as_ref() == "test"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_requirement_to_group() -> anyhow::Result<()> {
+        let original = r#"[project]
+name = "project"
+
+[dependency-groups]
+# Linting.
+lint = ["ruff"]
+test = [
+    # The test runner.
+    "pytest",
+]
+"#;
+        let mut pyproject = PyProjectTomlMut::from_toml(original, DependencyTarget::PyProjectToml)?;
+
+        pyproject.add_requirement_to_group(
+            &GroupName::from_str("test")?,
+            &Requirement::from_str("tox")?,
+        )?;
+        pyproject.add_requirement_to_group(
+            &GroupName::from_str("docs")?,
+            &Requirement::from_str("sphinx")?,
+        )?;
+
+        // Comments and the order of the existing groups are preserved.
+        let edited = pyproject.to_string();
+        assert!(
+            edited.contains("# Linting.\nlint = [\"ruff\"]\n"),
+            "{edited}"
+        );
+        assert!(edited.contains("# The test runner.\n"), "{edited}");
+        assert!(edited.contains("\"tox\""), "{edited}");
+        assert!(edited.contains("docs = [\"sphinx\"]"), "{edited}");
+        assert!(edited.find("lint =") < edited.find("test ="), "{edited}");
+
+        // A package that's already present is rejected, leaving the document as-is.
+        let err = pyproject
+            .add_requirement_to_group(
+                &GroupName::from_str("test")?,
+                &Requirement::from_str("pytest>=8 ; sys_platform == 'linux'")?,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::AlreadyPresent(name, _) if name.as_ref() == "pytest"));
+        assert_eq!(pyproject.to_string(), edited);
+
+        Ok(())
+    }
 }
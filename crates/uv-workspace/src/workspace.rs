@@ -2,6 +2,7 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 use glob::{GlobError, PatternError, glob};
@@ -18,13 +19,18 @@ use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
-use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
+use crate::dependency_groups::{
+    DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups, MergeMode,
+};
 use crate::pyproject::{
     Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
 };
 
 type WorkspaceMembers = Arc<BTreeMap<PackageName, WorkspaceMember>>;
 
+/// The candidate workspace members considered during discovery, with the decision for each.
+type MemberDecisions = Arc<[(PathBuf, MemberDecision)]>;
+
 /// Cache key for workspace discovery.
 ///
 /// Given this key, the discovered workspace member list is the same.
@@ -39,7 +45,19 @@ struct WorkspaceCacheKey {
 /// Avoid re-reading the `pyproject.toml` files in a workspace for each member by caching the
 /// workspace members by their workspace root.
 #[derive(Debug, Default, Clone)]
-pub struct WorkspaceCache(Arc<Mutex<FxHashMap<WorkspaceCacheKey, WorkspaceMembers>>>);
+pub struct WorkspaceCache {
+    members: Arc<Mutex<FxHashMap<WorkspaceCacheKey, (WorkspaceMembers, MemberDecisions)>>>,
+    /// The number of times the workspace members were read from the cache.
+    hits: Arc<AtomicUsize>,
+}
+
+impl WorkspaceCache {
+    /// Return the number of times the workspace members were read from the cache, rather than
+    /// discovered, e.g., to verify that the cache is effective.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum WorkspaceError {
@@ -75,6 +93,8 @@ pub enum WorkspaceError {
     Toml(PathBuf, #[source] Box<PyprojectTomlError>),
     #[error("Failed to normalize workspace member path")]
     Normalize(#[source] std::io::Error),
+    #[error("Workspace discovery is not permitted to read: `{}`", _0.user_display())]
+    DiscoveryBlocked(PathBuf),
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
@@ -94,10 +114,82 @@ pub struct DiscoveryOptions {
     pub stop_discovery_at: Option<PathBuf>,
     /// The strategy to use when discovering workspace members.
     pub members: MemberDiscovery,
+    /// The directories from which discovery may read files, as absolute paths.
+    ///
+    /// If set, discovery fails with [`WorkspaceError::DiscoveryBlocked`] rather than read a file
+    /// outside these directories. If unset, discovery may read any file.
+    pub readable_roots: Option<Vec<PathBuf>>,
+}
+
+impl DiscoveryOptions {
+    /// Ensure that discovery is permitted to read the given file.
+    ///
+    /// Both the file and the readable roots are canonicalized before they're compared, such that
+    /// neither `..` components nor symlinks can escape the roots.
+    pub fn check_readable(&self, path: &Path) -> Result<(), WorkspaceError> {
+        let Some(readable_roots) = &self.readable_roots else {
+            return Ok(());
+        };
+        let resolved = Self::resolve_readable(path);
+        if readable_roots
+            .iter()
+            .any(|root| resolved.starts_with(Self::resolve_readable(root)))
+        {
+            Ok(())
+        } else {
+            Err(WorkspaceError::DiscoveryBlocked(path.to_path_buf()))
+        }
+    }
+
+    /// Resolve the given path for comparison against the readable roots, falling back to a
+    /// lexical normalization if the path can't be canonicalized (e.g., if it doesn't exist).
+    fn resolve_readable(path: &Path) -> PathBuf {
+        path.simple_canonicalize()
+            .unwrap_or_else(|_| uv_fs::normalize_path_buf(path.to_path_buf()))
+    }
 }
 
 pub type RequiresPythonSources = BTreeMap<(PackageName, Option<GroupName>), VersionSpecifiers>;
 
+/// Why a candidate workspace member was or wasn't included during discovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberDecision {
+    /// The directory is the workspace root, which is a member if it's a project.
+    Root,
+    /// The directory is the current project, which is always a member.
+    Current,
+    /// The directory matched `tool.uv.workspace.members`.
+    Included,
+    /// The directory was ignored per [`DiscoveryOptions::members`].
+    Ignored,
+    /// The directory matched `tool.uv.workspace.exclude`.
+    Excluded,
+    /// The path matched `tool.uv.workspace.members`, but isn't a directory.
+    NotADirectory,
+    /// The directory is hidden, and doesn't contain a `pyproject.toml`.
+    Hidden,
+    /// The project is marked as unmanaged (`tool.uv.managed = false`).
+    Unmanaged,
+}
+
+impl std::fmt::Display for MemberDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Root => write!(f, "included as the workspace root"),
+            Self::Current => write!(f, "included as the current project"),
+            Self::Included => write!(f, "included by `tool.uv.workspace.members`"),
+            Self::Ignored => write!(f, "excluded by the discovery options"),
+            Self::Excluded => write!(f, "excluded by `tool.uv.workspace.exclude`"),
+            Self::NotADirectory => write!(f, "excluded as it is not a directory"),
+            Self::Hidden => write!(
+                f,
+                "excluded as a hidden directory without a `pyproject.toml`"
+            ),
+            Self::Unmanaged => write!(f, "excluded as it is marked as unmanaged"),
+        }
+    }
+}
+
 /// A workspace, consisting of a root directory and members. See [`ProjectWorkspace`].
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(serde::Serialize))]
@@ -121,6 +213,9 @@ pub struct Workspace {
     indexes: Vec<Index>,
     /// The `pyproject.toml` of the workspace root.
     pyproject_toml: PyProjectToml,
+    /// The candidate members considered during discovery, with the decision for each.
+    #[cfg_attr(test, serde(skip))]
+    member_decisions: MemberDecisions,
 }
 
 impl Workspace {
@@ -161,6 +256,7 @@ impl Workspace {
             .to_path_buf();
 
         let pyproject_path = project_path.join("pyproject.toml");
+        options.check_readable(&pyproject_path)?;
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
         let pyproject_toml = PyProjectToml::from_string(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
@@ -518,8 +614,12 @@ impl Workspace {
 
             // Get the requires-python for each enabled group on this package
             // We need to do full flattening here because include-group can transfer requires-python
-            let dependency_groups =
-                FlatDependencyGroups::from_pyproject_toml(member.root(), &member.pyproject_toml)?;
+            let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
+                member.root(),
+                &member.pyproject_toml,
+                &self.packages,
+                MergeMode::default(),
+            )?;
             let group_requires =
                 dependency_groups
                     .into_iter()
@@ -568,6 +668,8 @@ impl Workspace {
             let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
                 &self.install_path,
                 &self.pyproject_toml,
+                &self.packages,
+                MergeMode::default(),
             )?;
             Ok(dependency_groups.into_inner())
         }
@@ -710,6 +812,13 @@ impl Workspace {
         &self.packages
     }
 
+    /// The candidate members considered during discovery, in the order in which they were
+    /// considered, with the reason that each was or wasn't included, e.g., to explain why a
+    /// member wasn't discovered.
+    pub fn member_decisions(&self) -> &[(PathBuf, MemberDecision)] {
+        &self.member_decisions
+    }
+
     /// The sources table from the workspace `pyproject.toml`.
     pub fn sources(&self) -> &BTreeMap<PackageName, Sources> {
         &self.sources
@@ -770,33 +879,44 @@ impl Workspace {
         };
         let cache_entry = {
             // Acquire the lock for the minimal required region
-            let cache = cache.0.lock().expect("there was a panic in another thread");
-            cache.get(&cache_key).cloned()
+            let members = cache
+                .members
+                .lock()
+                .expect("there was a panic in another thread");
+            members.get(&cache_key).cloned()
         };
-        let mut workspace_members = if let Some(workspace_members) = cache_entry {
+        let (mut workspace_members, mut member_decisions) = if let Some(cache_entry) = cache_entry {
+            cache.hits.fetch_add(1, Ordering::Relaxed);
             trace!(
                 "Cached workspace members for: `{}`",
                 &workspace_root.simplified_display()
             );
-            workspace_members
+            cache_entry
         } else {
             trace!(
                 "Discovering workspace members for: `{}`",
                 &workspace_root.simplified_display()
             );
-            let workspace_members = Self::collect_members_only(
+            let (workspace_members, member_decisions) = Self::collect_members_only(
                 &workspace_root,
                 &workspace_definition,
                 &workspace_pyproject_toml,
                 options,
             )
             .await?;
+            let cache_entry = (
+                Arc::new(workspace_members),
+                MemberDecisions::from(member_decisions),
+            );
             {
                 // Acquire the lock for the minimal required region
-                let mut cache = cache.0.lock().expect("there was a panic in another thread");
-                cache.insert(cache_key, Arc::new(workspace_members.clone()));
+                let mut members = cache
+                    .members
+                    .lock()
+                    .expect("there was a panic in another thread");
+                members.insert(cache_key, cache_entry.clone());
             }
-            Arc::new(workspace_members)
+            cache_entry
         };
 
         // For the cases such as `MemberDiscovery::None`, add the current project if missing.
@@ -807,6 +927,14 @@ impl Workspace {
                     root_member.root.simplified_display()
                 );
 
+                member_decisions = member_decisions
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once((
+                        root_member.root.clone(),
+                        MemberDecision::Current,
+                    )))
+                    .collect();
                 Arc::make_mut(&mut workspace_members)
                     .insert(root_member.project.name.clone(), root_member);
             }
@@ -840,6 +968,7 @@ impl Workspace {
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
+            member_decisions,
         })
     }
 
@@ -848,8 +977,15 @@ impl Workspace {
         workspace_definition: &ToolUvWorkspace,
         workspace_pyproject_toml: &PyProjectToml,
         options: &DiscoveryOptions,
-    ) -> Result<BTreeMap<PackageName, WorkspaceMember>, WorkspaceError> {
+    ) -> Result<
+        (
+            BTreeMap<PackageName, WorkspaceMember>,
+            Vec<(PathBuf, MemberDecision)>,
+        ),
+        WorkspaceError,
+    > {
         let mut workspace_members = BTreeMap::new();
+        let mut member_decisions = Vec::new();
         // Avoid reading a `pyproject.toml` more than once.
         let mut seen = FxHashSet::default();
 
@@ -857,6 +993,7 @@ impl Workspace {
         // project. If it is the current project, it is added as such in the next step.
         if let Some(project) = &workspace_pyproject_toml.project {
             let pyproject_path = workspace_root.join("pyproject.toml");
+            options.check_readable(&pyproject_path)?;
             let contents = fs_err::read_to_string(&pyproject_path)?;
             let pyproject_toml = PyProjectToml::from_string(contents)
                 .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
@@ -867,6 +1004,7 @@ impl Workspace {
             );
 
             seen.insert(workspace_root.clone());
+            member_decisions.push((workspace_root.clone(), MemberDecision::Root));
             workspace_members.insert(
                 project.name.clone(),
                 WorkspaceMember {
@@ -908,6 +1046,7 @@ impl Workspace {
                         "Ignoring workspace member: `{}`",
                         member_root.simplified_display()
                     );
+                    member_decisions.push((member_root, MemberDecision::Ignored));
                     continue;
                 }
 
@@ -917,6 +1056,7 @@ impl Workspace {
                         "Ignoring workspace member: `{}`",
                         member_root.simplified_display()
                     );
+                    member_decisions.push((member_root, MemberDecision::Excluded));
                     continue;
                 }
 
@@ -927,6 +1067,7 @@ impl Workspace {
 
                 // Read the member `pyproject.toml`.
                 let pyproject_path = member_root.join("pyproject.toml");
+                options.check_readable(&pyproject_path)?;
                 let contents = match fs_err::tokio::read_to_string(&pyproject_path).await {
                     Ok(contents) => contents,
                     Err(err) => {
@@ -935,6 +1076,7 @@ impl Workspace {
                                 "Ignoring non-directory workspace member: `{}`",
                                 member_root.simplified_display()
                             );
+                            member_decisions.push((member_root, MemberDecision::NotADirectory));
                             continue;
                         }
 
@@ -950,6 +1092,7 @@ impl Workspace {
                                     "Ignoring hidden workspace member: `{}`",
                                     member_root.simplified_display()
                                 );
+                                member_decisions.push((member_root, MemberDecision::Hidden));
                                 continue;
                             }
 
@@ -977,6 +1120,7 @@ impl Workspace {
                         "Project `{}` is marked as unmanaged; omitting from workspace members",
                         pyproject_toml.project.as_ref().unwrap().name
                     );
+                    member_decisions.push((member_root, MemberDecision::Unmanaged));
                     continue;
                 }
 
@@ -989,6 +1133,7 @@ impl Workspace {
                     "Adding discovered workspace member: `{}`",
                     member_root.simplified_display()
                 );
+                member_decisions.push((member_root.clone(), MemberDecision::Included));
 
                 if let Some(existing) = workspace_members.insert(
                     project.name.clone(),
@@ -1021,7 +1166,7 @@ impl Workspace {
                 return Err(WorkspaceError::NestedWorkspace(member.root.clone()));
             }
         }
-        Ok(workspace_members)
+        Ok((workspace_members, member_decisions))
     }
 }
 
@@ -1184,6 +1329,7 @@ impl ProjectWorkspace {
     ) -> Result<Self, WorkspaceError> {
         // Read the current `pyproject.toml`.
         let pyproject_path = project_root.join("pyproject.toml");
+        options.check_readable(&pyproject_path)?;
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
         let pyproject_toml = PyProjectToml::from_string(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
@@ -1206,6 +1352,7 @@ impl ProjectWorkspace {
     ) -> Result<Option<Self>, WorkspaceError> {
         // Read the `pyproject.toml`.
         let pyproject_path = install_path.join("pyproject.toml");
+        options.check_readable(&pyproject_path)?;
         let Ok(contents) = fs_err::tokio::read_to_string(&pyproject_path).await else {
             // No `pyproject.toml`, but there may still be a `setup.py` or `setup.cfg`.
             return Ok(None);
@@ -1339,6 +1486,10 @@ impl ProjectWorkspace {
                     sources: workspace_sources,
                     indexes: Vec::default(),
                     pyproject_toml: project_pyproject_toml.clone(),
+                    member_decisions: MemberDecisions::from([(
+                        project_path.clone(),
+                        MemberDecision::Root,
+                    )]),
                 },
             });
         };
@@ -1395,6 +1546,7 @@ async fn find_workspace(
         );
 
         // Read the `pyproject.toml`.
+        options.check_readable(&pyproject_path)?;
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
         let pyproject_toml = PyProjectToml::from_string(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
@@ -1583,10 +1735,50 @@ impl VirtualProject {
 
         // Read the current `pyproject.toml`.
         let pyproject_path = project_root.join("pyproject.toml");
+        options.check_readable(&pyproject_path)?;
         let contents = fs_err::tokio::read_to_string(&pyproject_path).await?;
         let pyproject_toml = PyProjectToml::from_string(contents)
             .map_err(|err| WorkspaceError::Toml(pyproject_path.clone(), Box::new(err)))?;
 
+        Self::from_pyproject_toml_impl(
+            project_root,
+            pyproject_toml,
+            options,
+            cache,
+            default_missing_workspace,
+        )
+        .await
+    }
+
+    /// Equivalent to [`VirtualProject::discover_defaulted`], but for an already-parsed
+    /// `pyproject.toml` at the given project root, e.g., one that was edited in memory.
+    ///
+    /// The given `pyproject.toml` is used in lieu of the one on disk (if any); any other files
+    /// (e.g., the workspace root and members) are still read from disk.
+    ///
+    /// This method requires an absolute path and panics otherwise.
+    pub async fn from_pyproject_toml(
+        project_root: &Path,
+        pyproject_toml: PyProjectToml,
+        options: &DiscoveryOptions,
+        cache: &WorkspaceCache,
+    ) -> Result<Self, WorkspaceError> {
+        assert!(
+            project_root.is_absolute(),
+            "virtual project discovery with relative path"
+        );
+        Self::from_pyproject_toml_impl(project_root, pyproject_toml, options, cache, true).await
+    }
+
+    /// Construct the project or virtual workspace rooted at the given directory, given its
+    /// `pyproject.toml`.
+    async fn from_pyproject_toml_impl(
+        project_root: &Path,
+        pyproject_toml: PyProjectToml,
+        options: &DiscoveryOptions,
+        cache: &WorkspaceCache,
+        default_missing_workspace: bool,
+    ) -> Result<Self, WorkspaceError> {
         if let Some(project) = pyproject_toml.project.as_ref() {
             // If the `pyproject.toml` contains a `[project]` table, it's a project.
             let project = ProjectWorkspace::from_project(
@@ -1640,7 +1832,9 @@ impl VirtualProject {
 
             Ok(Self::NonProject(workspace))
         } else {
-            Err(WorkspaceError::MissingProject(pyproject_path))
+            Err(WorkspaceError::MissingProject(
+                project_root.join("pyproject.toml"),
+            ))
         }
     }
 
@@ -1718,7 +1912,7 @@ mod tests {
     use uv_pypi_types::DependencyGroupSpecifier;
 
     use crate::pyproject::PyProjectToml;
-    use crate::workspace::{DiscoveryOptions, ProjectWorkspace};
+    use crate::workspace::{DiscoveryOptions, MemberDecision, ProjectWorkspace};
     use crate::{WorkspaceCache, WorkspaceError};
 
     async fn workspace_test(folder: &str) -> (ProjectWorkspace, String) {
@@ -2782,4 +2976,225 @@ bar = ["b"]
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn readable_roots() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        // Create the root.
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["tqdm>=4,<5"]
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            "#,
+        )?;
+
+        // Create an included package (`seeds`).
+        root.child("packages")
+            .child("seeds")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+            dependencies = ["idna==3.6"]
+            "#,
+            )?;
+
+        // If the workspace root is readable, discovery succeeds.
+        let project = ProjectWorkspace::discover(
+            root.as_ref(),
+            &DiscoveryOptions {
+                readable_roots: Some(vec![root.path().to_path_buf()]),
+                ..DiscoveryOptions::default()
+            },
+            &WorkspaceCache::default(),
+        )
+        .await?;
+        assert_eq!(project.workspace().packages().len(), 2);
+
+        // If only the workspace root's `pyproject.toml` is readable, reading the member fails.
+        let error = ProjectWorkspace::discover(
+            root.as_ref(),
+            &DiscoveryOptions {
+                readable_roots: Some(vec![root.child("pyproject.toml").path().to_path_buf()]),
+                ..DiscoveryOptions::default()
+            },
+            &WorkspaceCache::default(),
+        )
+        .await
+        .unwrap_err();
+        let root_escaped = regex::escape(root.path().to_string_lossy().as_ref());
+        let filters = vec![(root_escaped.as_str(), "[ROOT]")];
+        insta::with_settings!({filters => filters}, {
+            assert_snapshot!(
+                error,
+            @"Workspace discovery is not permitted to read: `[ROOT]/packages/seeds/pyproject.toml`");
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn readable_roots_traversal() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        // Create the workspace root, with a member outside the workspace directory.
+        root.child("albatross").child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["../seeds"]
+            "#,
+        )?;
+        root.child("seeds").child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "seeds"
+            version = "1.0.0"
+            requires-python = ">=3.12"
+            "#,
+        )?;
+
+        // A `..` component can't escape the readable roots.
+        let error = ProjectWorkspace::discover(
+            root.child("albatross").as_ref(),
+            &DiscoveryOptions {
+                readable_roots: Some(vec![root.child("albatross").path().to_path_buf()]),
+                ..DiscoveryOptions::default()
+            },
+            &WorkspaceCache::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(error, WorkspaceError::DiscoveryBlocked(_)),
+            "{error}"
+        );
+
+        // Nor can a symlink.
+        #[cfg(unix)]
+        {
+            root.child("albatross").child("pyproject.toml").write_str(
+                r#"
+                [project]
+                name = "albatross"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+
+                [tool.uv.workspace]
+                members = ["packages/*"]
+                "#,
+            )?;
+            fs_err::create_dir_all(root.child("albatross").child("packages"))?;
+            fs_err::os::unix::fs::symlink(
+                root.child("seeds").path(),
+                root.child("albatross").child("packages").child("seeds"),
+            )?;
+            let error = ProjectWorkspace::discover(
+                root.child("albatross").as_ref(),
+                &DiscoveryOptions {
+                    readable_roots: Some(vec![root.child("albatross").path().to_path_buf()]),
+                    ..DiscoveryOptions::default()
+                },
+                &WorkspaceCache::default(),
+            )
+            .await
+            .unwrap_err();
+            assert!(
+                matches!(error, WorkspaceError::DiscoveryBlocked(_)),
+                "{error}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn member_decisions() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        // Create the root.
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            exclude = ["packages/excluded"]
+            "#,
+        )?;
+
+        // Create an included package (`seeds`), an excluded package, and an unmanaged package.
+        for (name, extra) in [
+            ("seeds", ""),
+            ("excluded", ""),
+            ("unmanaged", "[tool.uv]\nmanaged = false\n"),
+        ] {
+            root.child("packages")
+                .child(name)
+                .child("pyproject.toml")
+                .write_str(&format!(
+                    "[project]\nname = \"{name}\"\nversion = \"1.0.0\"\n{extra}"
+                ))?;
+        }
+
+        // Create a hidden directory without a `pyproject.toml`, and a file.
+        root.child("packages").child(".hidden").create_dir_all()?;
+        root.child("packages").child("file.txt").touch()?;
+
+        let project = ProjectWorkspace::discover(
+            root.as_ref(),
+            &DiscoveryOptions::default(),
+            &WorkspaceCache::default(),
+        )
+        .await?;
+        let decisions = project
+            .workspace()
+            .member_decisions()
+            .iter()
+            .map(|(path, decision)| {
+                (
+                    path.strip_prefix(root.path())
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string(),
+                    *decision,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decisions,
+            [
+                (String::new(), MemberDecision::Root),
+                ("packages/.hidden".to_string(), MemberDecision::Hidden),
+                ("packages/excluded".to_string(), MemberDecision::Excluded),
+                (
+                    "packages/file.txt".to_string(),
+                    MemberDecision::NotADirectory
+                ),
+                ("packages/seeds".to_string(), MemberDecision::Included),
+                ("packages/unmanaged".to_string(), MemberDecision::Unmanaged),
+            ]
+        );
+
+        Ok(())
+    }
 }
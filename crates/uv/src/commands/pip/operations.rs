@@ -16,7 +16,7 @@ use uv_configuration::{
     ExtrasSpecification, Overrides, PackageConfigSettings, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
+use uv_distribution::{DependencyGroupsOptions, DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
     CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
     ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
@@ -213,6 +213,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 None,
                 build_dispatch.locations(),
                 build_dispatch.sources(),
+                &DependencyGroupsOptions::default(),
                 build_dispatch.workspace_cache(),
             )
             .await
@@ -223,6 +224,9 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                     e
                 )
             })?;
+            for warning in metadata.warnings() {
+                warn_user!("{warning}");
+            }
 
             // Complain if dependency groups are named that don't appear.
             for name in groups.explicit_names() {
@@ -233,8 +237,13 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                     ))?;
                 }
             }
-            // Apply dependency-groups
+            // Apply dependency-groups, omitting any explicit groups that weren't named directly.
             for (group_name, group) in &metadata.dependency_groups {
+                if metadata.is_explicit(group_name)
+                    && !groups.explicit_names().any(|name| name == group_name)
+                {
+                    continue;
+                }
                 if groups.contains(group_name) {
                     requirements.extend(group.iter().cloned().map(|group| Requirement {
                         origin: Some(RequirementOrigin::Group(
@@ -844,6 +844,7 @@ fn edits(
                 marker,
                 extra,
                 group,
+                requires_uv,
             }) => {
                 let credentials = uv_auth::Credentials::from_url(&git);
                 if let Some(credentials) = credentials {
@@ -862,6 +863,7 @@ fn edits(
                     marker,
                     extra,
                     group,
+                    requires_uv,
                 })
             }
             _ => source,